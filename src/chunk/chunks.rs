@@ -64,9 +64,15 @@ impl CharChunks {
 
     #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Self {
+        Self::from_str_with_origin(s, None)
+    }
+
+    pub fn from_str_with_origin(s: &str, origin: Option<Loc>) -> Self {
         let mut line = 0;
         let mut pos = 0;
 
+        let origin = origin.map(Box::new);
+
         let chunks: Vec<CharChunk> = s
             .chars()
             .map(|content| {
@@ -75,6 +81,7 @@ impl CharChunks {
                         file: None,
                         line,
                         pos,
+                        origin: origin.clone(),
                     },
                     content,
                 };
@@ -272,6 +279,10 @@ impl StringChunks {
         StringChunks::from_char_chunks(CharChunks::from_str(s))
     }
 
+    pub fn from_str_with_origin(s: &str, origin: Option<Loc>) -> Self {
+        StringChunks::from_char_chunks(CharChunks::from_str_with_origin(s, origin))
+    }
+
     pub fn from_string(s: String) -> Self {
         Self::from_str(&s)
     }
@@ -4,6 +4,24 @@ use std::error;
 use std::fmt;
 use std::io;
 
+#[derive(Debug, Eq, PartialEq)]
+pub struct ParsingError {
+    pub loc: Option<Loc>,
+    pub desc: String,
+}
+
+impl fmt::Display for ParsingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ref loc) = self.loc {
+            write!(f, "parsing error at {}: {}", loc.to_string(), self.desc)
+        } else {
+            write!(f, "parsing error: {}", self.desc)
+        }
+    }
+}
+
+impl error::Error for ParsingError {}
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct SyntacticError {
     pub loc: Option<Loc>,
@@ -22,6 +40,39 @@ impl fmt::Display for SyntacticError {
 
 impl error::Error for SyntacticError {}
 
+#[derive(Debug, Eq, PartialEq)]
+pub struct KeywordError {
+    pub loc: Option<Loc>,
+    pub word: String,
+    pub suggestions: Vec<String>,
+}
+
+impl KeywordError {
+    pub fn desc(&self) -> String {
+        if self.suggestions.is_empty() {
+            format!("unknown keyword {}", self.word)
+        } else {
+            format!(
+                "unknown keyword {}, did you mean {}?",
+                self.word,
+                self.suggestions.join(" or ")
+            )
+        }
+    }
+}
+
+impl fmt::Display for KeywordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ref loc) = self.loc {
+            write!(f, "syntactic error at {}: {}", loc.to_string(), self.desc())
+        } else {
+            write!(f, "syntactic error: {}", self.desc())
+        }
+    }
+}
+
+impl error::Error for KeywordError {}
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct SemanticError {
     pub loc: Option<Loc>,
@@ -40,19 +91,202 @@ impl fmt::Display for SemanticError {
 
 impl error::Error for SemanticError {}
 
+#[derive(Debug, Eq, PartialEq)]
+pub struct TypeError {
+    pub loc: Option<Loc>,
+    pub desc: String,
+}
+
+impl fmt::Display for TypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ref loc) = self.loc {
+            write!(f, "type error at {}: {}", loc.to_string(), self.desc)
+        } else {
+            write!(f, "type error: {}", self.desc)
+        }
+    }
+}
+
+impl error::Error for TypeError {}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct RuntimeError {
+    pub loc: Option<Loc>,
+    pub desc: String,
+}
+
+impl fmt::Display for RuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ref loc) = self.loc {
+            write!(f, "runtime error at {}: {}", loc.to_string(), self.desc)
+        } else {
+            write!(f, "runtime error: {}", self.desc)
+        }
+    }
+}
+
+impl error::Error for RuntimeError {}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct InternalError {
+    pub loc: Option<Loc>,
+    pub desc: String,
+}
+
+impl fmt::Display for InternalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ref loc) = self.loc {
+            write!(f, "internal error at {}: {}", loc.to_string(), self.desc)
+        } else {
+            write!(f, "internal error: {}", self.desc)
+        }
+    }
+}
+
+impl error::Error for InternalError {}
+
 #[derive(Debug)]
 pub enum Error {
+    IO(io::Error),
+    Parsing(ParsingError),
     Syntactic(SyntacticError),
+    Keyword(KeywordError),
     Semantic(SemanticError),
-    IO(io::Error),
+    Type(TypeError),
+    Runtime(RuntimeError),
+    Internal(InternalError),
+}
+
+pub const IO_EXIT_CODE: i32 = 2;
+pub const PARSING_EXIT_CODE: i32 = 3;
+pub const SYNTACTIC_EXIT_CODE: i32 = 4;
+pub const SEMANTIC_EXIT_CODE: i32 = 5;
+pub const TYPE_EXIT_CODE: i32 = 6;
+pub const RUNTIME_EXIT_CODE: i32 = 7;
+pub const INTERNAL_EXIT_CODE: i32 = 8;
+
+impl Error {
+    pub fn loc(&self) -> Option<Loc> {
+        match self {
+            Self::IO(_) => None,
+            Self::Parsing(err) => err.loc.clone(),
+            Self::Syntactic(err) => err.loc.clone(),
+            Self::Keyword(err) => err.loc.clone(),
+            Self::Semantic(err) => err.loc.clone(),
+            Self::Type(err) => err.loc.clone(),
+            Self::Runtime(err) => err.loc.clone(),
+            Self::Internal(err) => err.loc.clone(),
+        }
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::IO(_) => IO_EXIT_CODE,
+            Self::Parsing(_) => PARSING_EXIT_CODE,
+            Self::Syntactic(_) | Self::Keyword(_) => SYNTACTIC_EXIT_CODE,
+            Self::Semantic(_) => SEMANTIC_EXIT_CODE,
+            Self::Type(_) => TYPE_EXIT_CODE,
+            Self::Runtime(_) => RUNTIME_EXIT_CODE,
+            Self::Internal(_) => INTERNAL_EXIT_CODE,
+        }
+    }
+
+    pub fn kind_name(&self) -> &'static str {
+        match self {
+            Self::IO(_) => "io",
+            Self::Parsing(_) => "parsing",
+            Self::Syntactic(_) | Self::Keyword(_) => "syntactic",
+            Self::Semantic(_) => "semantic",
+            Self::Type(_) => "type",
+            Self::Runtime(_) => "runtime",
+            Self::Internal(_) => "internal",
+        }
+    }
+
+    pub fn desc(&self) -> String {
+        match self {
+            Self::IO(err) => err.to_string(),
+            Self::Parsing(err) => err.desc.clone(),
+            Self::Syntactic(err) => err.desc.clone(),
+            Self::Keyword(err) => err.desc(),
+            Self::Semantic(err) => err.desc.clone(),
+            Self::Type(err) => err.desc.clone(),
+            Self::Runtime(err) => err.desc.clone(),
+            Self::Internal(err) => err.desc.clone(),
+        }
+    }
+
+    pub fn suggestions(&self) -> Vec<String> {
+        match self {
+            Self::Keyword(err) => err.suggestions.clone(),
+            _ => vec![],
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let loc = self.loc().map(|loc| loc.source_loc());
+
+        let file = match loc.as_ref().and_then(|loc| loc.file.as_ref()) {
+            Some(file) => json_string(file),
+            None => "null".into(),
+        };
+
+        let (line, pos) = match loc {
+            Some(loc) => (loc.line.to_string(), loc.pos.to_string()),
+            None => ("null".into(), "null".into()),
+        };
+
+        let suggestions = self
+            .suggestions()
+            .iter()
+            .map(|suggestion| json_string(suggestion))
+            .collect::<Vec<String>>()
+            .join(",");
+
+        format!(
+            "{{\"severity\":\"error\",\"kind\":{},\"exit_code\":{},\"file\":{},\"line\":{},\"pos\":{},\"message\":{},\"suggestions\":[{}]}}",
+            json_string(self.kind_name()),
+            self.exit_code(),
+            file,
+            line,
+            pos,
+            json_string(&self.desc()),
+            suggestions
+        )
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::from("\"");
+
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+
+    json
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::IO(err) => err.fmt(f),
+            Self::Parsing(err) => err.fmt(f),
             Self::Syntactic(err) => err.fmt(f),
+            Self::Keyword(err) => err.fmt(f),
             Self::Semantic(err) => err.fmt(f),
-            Self::IO(err) => err.fmt(f),
+            Self::Type(err) => err.fmt(f),
+            Self::Runtime(err) => err.fmt(f),
+            Self::Internal(err) => err.fmt(f),
         }
     }
 }
@@ -60,9 +294,14 @@ impl fmt::Display for Error {
 impl error::Error for Error {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match self {
+            Self::IO(err) => Some(err),
+            Self::Parsing(err) => err.source(),
             Self::Syntactic(err) => err.source(),
+            Self::Keyword(err) => err.source(),
             Self::Semantic(err) => err.source(),
-            Self::IO(err) => err.source(),
+            Self::Type(err) => err.source(),
+            Self::Runtime(err) => err.source(),
+            Self::Internal(err) => err.source(),
         }
     }
 }
@@ -72,3 +311,75 @@ impl convert::From<io::Error> for Error {
         Self::IO(err)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn error_source_and_exit_code() {
+        use super::{Error, SyntacticError};
+        use super::{IO_EXIT_CODE, SYNTACTIC_EXIT_CODE};
+        use std::error::Error as StdError;
+        use std::io;
+
+        let err: Error = io::Error::new(io::ErrorKind::NotFound, "no such file").into();
+
+        assert_eq!(err.exit_code(), IO_EXIT_CODE);
+        assert!(err.source().is_some());
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            "no such file".to_string()
+        );
+
+        let err = Error::Syntactic(SyntacticError {
+            loc: None,
+            desc: "expected a form".into(),
+        });
+
+        assert_eq!(err.exit_code(), SYNTACTIC_EXIT_CODE);
+        assert!(err.source().is_none());
+        assert_eq!(
+            err.to_string(),
+            "syntactic error: expected a form".to_string()
+        );
+    }
+
+    #[test]
+    fn error_to_json() {
+        use super::{Error, KeywordError, SyntacticError};
+        use crate::loc::Loc;
+        use std::io;
+
+        let err = Error::Syntactic(SyntacticError {
+            loc: Some(Loc {
+                file: Some("main.at".into()),
+                line: 2,
+                pos: 7,
+                origin: None,
+            }),
+            desc: "unexpected \"x\"\n".into(),
+        });
+
+        assert_eq!(
+            err.to_json(),
+            r#"{"severity":"error","kind":"syntactic","exit_code":4,"file":"main.at","line":2,"pos":7,"message":"unexpected \"x\"\n","suggestions":[]}"#
+        );
+
+        let err: Error = io::Error::new(io::ErrorKind::NotFound, "no such file").into();
+
+        assert_eq!(
+            err.to_json(),
+            r#"{"severity":"error","kind":"io","exit_code":2,"file":null,"line":null,"pos":null,"message":"no such file","suggestions":[]}"#
+        );
+
+        let err = Error::Keyword(KeywordError {
+            loc: None,
+            word: "mdule".into(),
+            suggestions: vec!["module".into()],
+        });
+
+        assert_eq!(
+            err.to_json(),
+            r#"{"severity":"error","kind":"syntactic","exit_code":4,"file":null,"line":null,"pos":null,"message":"unknown keyword mdule, did you mean module?","suggestions":["module"]}"#
+        );
+    }
+}
@@ -2,6 +2,8 @@ pub mod chunk;
 pub mod error;
 pub mod loc;
 pub mod result;
+#[cfg(test)]
+mod snapshot;
 pub mod syntax;
 pub mod token;
 pub mod value;
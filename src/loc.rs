@@ -5,6 +5,7 @@ pub struct Loc {
     pub file: Option<String>,
     pub line: usize,
     pub pos: usize,
+    pub origin: Option<Box<Loc>>,
 }
 
 impl Loc {
@@ -12,20 +13,73 @@ impl Loc {
         Loc::default()
     }
 
+    pub fn synthetic(origin: Loc) -> Self {
+        Loc {
+            origin: Some(Box::new(origin)),
+            ..Loc::default()
+        }
+    }
+
+    pub fn is_synthetic(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    pub fn source_loc(&self) -> Loc {
+        match self.origin {
+            Some(ref origin) => origin.source_loc(),
+            None => self.clone(),
+        }
+    }
+
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        let file = self.file.clone().unwrap_or_else(|| "none".into());
-        format!("(file: {}, line: {}, pos: {})", file, self.line, self.pos)
+        format!("{}", self)
     }
 }
 
 impl fmt::Display for Loc {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let file = self.file.clone().unwrap_or_else(|| "none".into());
-        write!(
-            f,
-            "(file: {}, line: {}, pos: {})",
-            file, self.line, self.pos
-        )
+
+        if let Some(ref origin) = self.origin {
+            write!(
+                f,
+                "(file: {}, line: {}, pos: {}, origin: {})",
+                file, self.line, self.pos, origin
+            )
+        } else {
+            write!(
+                f,
+                "(file: {}, line: {}, pos: {})",
+                file, self.line, self.pos
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn synthetic_loc() {
+        use super::Loc;
+
+        let origin = Loc {
+            file: Some("main.at".into()),
+            line: 3,
+            pos: 4,
+            origin: None,
+        };
+
+        let mut loc = Loc::synthetic(origin.clone());
+        loc.pos = 7;
+
+        assert!(loc.is_synthetic());
+        assert!(!origin.is_synthetic());
+        assert_eq!(loc.source_loc(), origin);
+        assert_eq!(Loc::synthetic(loc.clone()).source_loc(), origin);
+        assert_eq!(
+            loc.to_string(),
+            "(file: none, line: 0, pos: 7, origin: (file: main.at, line: 3, pos: 4))".to_string()
+        );
     }
 }
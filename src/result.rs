@@ -1,3 +1,54 @@
 use crate::error::Error;
 
 pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, Default)]
+pub struct Diagnosed<T> {
+    pub value: T,
+    pub diagnostics: Vec<Error>,
+    pub warnings: Vec<Error>,
+}
+
+impl<T> Diagnosed<T> {
+    pub fn new(value: T) -> Self {
+        Diagnosed {
+            value,
+            diagnostics: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, err: Error) {
+        self.diagnostics.push(err)
+    }
+
+    pub fn warn(&mut self, err: Error) {
+        self.warnings.push(err)
+    }
+
+    pub fn push_result<U>(&mut self, res: Result<U>) -> Option<U> {
+        match res {
+            Ok(value) => Some(value),
+            Err(err) => {
+                self.push(err);
+                None
+            }
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+
+    pub fn is_err(&self) -> bool {
+        !self.is_ok()
+    }
+
+    pub fn into_result(mut self) -> Result<T> {
+        if self.diagnostics.is_empty() {
+            Ok(self.value)
+        } else {
+            Err(self.diagnostics.remove(0))
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const SNAPSHOTS_DIR: &str = "tests/snapshots";
+
+pub const UPDATE_SNAPSHOTS_VAR: &str = "ASTER_UPDATE_SNAPSHOTS";
+
+pub fn snapshot_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join(SNAPSHOTS_DIR)
+        .join(format!("{}.snap", name))
+}
+
+pub fn assert_snapshot(name: &str, actual: &str) {
+    assert_file_snapshot(&snapshot_path(name), &format!("{}\n", actual));
+}
+
+pub fn assert_file_snapshot(path: &Path, actual: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(path);
+
+    if env::var(UPDATE_SNAPSHOTS_VAR).is_ok() {
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "missing snapshot {}, run with {}=1 to create it",
+            path.display(),
+            UPDATE_SNAPSHOTS_VAR
+        )
+    });
+
+    assert_eq!(
+        actual,
+        expected,
+        "snapshot {} differs, run with {}=1 to update it",
+        path.display(),
+        UPDATE_SNAPSHOTS_VAR
+    );
+}
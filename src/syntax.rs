@@ -1,5 +1,7 @@
-use crate::error::{Error, SyntacticError};
+use crate::error::{Error, KeywordError, SyntacticError};
+use crate::loc::Loc;
 use crate::result::Result;
+use crate::token::Token;
 use std::convert;
 use std::fmt;
 
@@ -12,7 +14,7 @@ pub const KEYWORDS: [&str; 55] = [
 ];
 
 pub fn is_keyword(s: &str) -> bool {
-    KEYWORDS.iter().any(|&k| k == s)
+    KEYWORDS.contains(&s)
 }
 
 pub fn is_value_keyword(s: &str) -> bool {
@@ -23,6 +25,21 @@ pub fn is_type_keyword(s: &str) -> bool {
     is_keyword(s) && is_type_symbol_start_char(s.chars().next().unwrap())
 }
 
+pub const FORM_KEYWORDS: [&str; 16] = [
+    "module", "block", "import", "export", "attrs", "type", "sig", "val", "fun", "case", "match",
+    "map", "vec", "arr", "list", "pair",
+];
+
+pub fn is_form_keyword(s: &str) -> bool {
+    FORM_KEYWORDS.contains(&s)
+}
+
+pub const MODULE_ATTRIBUTES: [&str; 3] = ["entry", "noPrelude", "strict"];
+
+pub fn is_module_attribute(s: &str) -> bool {
+    MODULE_ATTRIBUTES.contains(&s)
+}
+
 pub const IGNORE: &str = "_";
 
 pub fn is_ignore_keyword(s: &str) -> bool {
@@ -209,13 +226,24 @@ impl Keyword {
             "Map" => Ok(Keyword::MapT),
             "Fun" => Ok(Keyword::FunT),
             "Type" => Ok(Keyword::TypeT),
-            _ => Err(Error::Syntactic(SyntacticError {
+            _ => Err(Error::Keyword(KeywordError {
                 loc: None,
-                desc: "expected keyword".into(),
+                word: s.into(),
+                suggestions: suggestions(s, KEYWORDS.iter().copied()),
             })),
         }
     }
 
+    pub fn from_token(token: &Token) -> Result<Self> {
+        Keyword::from_str(&token.to_string()).map_err(|err| match err {
+            Error::Keyword(err) => Error::Keyword(KeywordError {
+                loc: token.loc(),
+                ..err
+            }),
+            err => err,
+        })
+    }
+
     pub fn from_string(s: String) -> Result<Self> {
         Keyword::from_str(&s)
     }
@@ -237,6 +265,101 @@ impl convert::TryFrom<String> for Keyword {
     }
 }
 
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct SyntaxConfig {
+    pub keywords: Vec<String>,
+    pub type_keywords: Vec<String>,
+    pub reserved_symbols: Vec<String>,
+    pub builtins: Vec<String>,
+}
+
+impl Default for SyntaxConfig {
+    fn default() -> Self {
+        SyntaxConfig {
+            keywords: KEYWORDS
+                .iter()
+                .filter(|k| !is_type_keyword(k))
+                .map(|k| k.to_string())
+                .collect(),
+            type_keywords: KEYWORDS
+                .iter()
+                .filter(|k| is_type_keyword(k))
+                .map(|k| k.to_string())
+                .collect(),
+            reserved_symbols: Vec::new(),
+            builtins: Vec::new(),
+        }
+    }
+}
+
+impl SyntaxConfig {
+    pub fn new() -> Self {
+        SyntaxConfig::default()
+    }
+
+    pub fn is_keyword(&self, s: &str) -> bool {
+        self.keywords.iter().any(|k| k == s) || self.is_type_keyword(s)
+    }
+
+    pub fn is_type_keyword(&self, s: &str) -> bool {
+        self.type_keywords.iter().any(|k| k == s)
+    }
+
+    pub fn is_reserved_symbol(&self, s: &str) -> bool {
+        self.reserved_symbols.iter().any(|r| r == s)
+    }
+
+    pub fn is_builtin(&self, s: &str) -> bool {
+        self.builtins.iter().any(|b| b == s)
+    }
+
+    fn check_registrable(&self, s: &str) -> Result<()> {
+        if !is_symbol(s) {
+            return Err(Error::Syntactic(SyntacticError {
+                loc: None,
+                desc: format!("expected {} to be a symbol", s),
+            }));
+        }
+
+        if self.is_reserved_symbol(s) || self.is_builtin(s) {
+            return Err(Error::Syntactic(SyntacticError {
+                loc: None,
+                desc: format!("{} is already registered", s),
+            }));
+        }
+
+        Ok(())
+    }
+
+    pub fn register_reserved_symbol(&mut self, s: &str) -> Result<()> {
+        self.check_registrable(s)?;
+        self.reserved_symbols.push(s.into());
+
+        Ok(())
+    }
+
+    pub fn register_builtin(&mut self, s: &str) -> Result<()> {
+        self.check_registrable(s)?;
+        self.builtins.push(s.into());
+
+        Ok(())
+    }
+
+    pub fn check_definition_name(&self, s: &str, loc: Option<Loc>) -> Result<()> {
+        let desc = if self.is_keyword(s) {
+            format!("cannot define keyword {}", s)
+        } else if self.is_reserved_symbol(s) {
+            format!("cannot define reserved symbol {}", s)
+        } else if self.is_builtin(s) {
+            format!("cannot redefine builtin {}", s)
+        } else {
+            return Ok(());
+        };
+
+        Err(Error::Syntactic(SyntacticError { loc, desc }))
+    }
+}
+
 pub fn is_whitespace(s: &str) -> bool {
     s.chars().all(|c| c.is_ascii_whitespace())
 }
@@ -254,6 +377,10 @@ pub fn is_separator_char(c: char) -> bool {
         || c == DOUBLE_QUOTE
         || c == FORM_START
         || c == FORM_END
+        || c == BRACKET_FORM_START
+        || c == BRACKET_FORM_END
+        || c == BRACE_FORM_START
+        || c == BRACE_FORM_END
 }
 
 pub const COMMENT_MARK: char = '#';
@@ -268,6 +395,16 @@ pub fn is_doc_comment_mark(s: &str) -> bool {
     s == [COMMENT_MARK.to_string(), COMMENT_MARK_POSTFIX.to_string()].join("")
 }
 
+pub const SHEBANG_PATH_START: char = '/';
+
+pub fn is_shebang(s: &str) -> bool {
+    let mut chars = s.chars();
+
+    chars.next() == Some(COMMENT_MARK)
+        && chars.next() == Some(COMMENT_MARK_POSTFIX)
+        && chars.next() == Some(SHEBANG_PATH_START)
+}
+
 pub fn is_uint_literal(s: &str) -> bool {
     if s.is_empty() {
         return false;
@@ -402,6 +539,10 @@ pub fn is_symbol_char(c: char) -> bool {
         || (c != COMMENT_MARK
             && c != FORM_START
             && c != FORM_END
+            && c != BRACKET_FORM_START
+            && c != BRACKET_FORM_END
+            && c != BRACE_FORM_START
+            && c != BRACE_FORM_END
             && c != SINGLE_QUOTE
             && c != DOUBLE_QUOTE
             && !c.is_whitespace())
@@ -490,7 +631,13 @@ pub fn symbol_name(s: &str) -> String {
 }
 
 pub fn symbol_with_qualifier(s: &str, qualifier: &str) -> String {
-    vec![qualifier, s].join(".")
+    [qualifier, s].join(".")
+}
+
+pub const GLOB_IMPORT_MARK: &str = "*";
+
+pub fn is_glob_path(s: &str) -> bool {
+    is_qualified(s) && symbol_name(s) == GLOB_IMPORT_MARK && !symbol_qualifier(s).is_empty()
 }
 
 pub fn is_type_path_symbol(s: &str) -> bool {
@@ -517,8 +664,739 @@ pub fn is_form_end(s: &str) -> bool {
     s == FORM_END.to_string()
 }
 
+pub const BRACKET_FORM_START: char = '[';
+
+pub fn is_bracket_form_start(s: &str) -> bool {
+    s == BRACKET_FORM_START.to_string()
+}
+
+pub const BRACKET_FORM_END: char = ']';
+
+pub fn is_bracket_form_end(s: &str) -> bool {
+    s == BRACKET_FORM_END.to_string()
+}
+
+pub const BRACE_FORM_START: char = '{';
+
+pub fn is_brace_form_start(s: &str) -> bool {
+    s == BRACE_FORM_START.to_string()
+}
+
+pub const BRACE_FORM_END: char = '}';
+
+pub fn is_brace_form_end(s: &str) -> bool {
+    s == BRACE_FORM_END.to_string()
+}
+
+pub fn is_any_form_start(s: &str) -> bool {
+    is_form_start(s) || is_bracket_form_start(s) || is_brace_form_start(s)
+}
+
+pub fn is_any_form_end(s: &str) -> bool {
+    is_form_end(s) || is_bracket_form_end(s) || is_brace_form_end(s)
+}
+
+pub fn form_end_of(start: &str) -> Option<char> {
+    match start.chars().next() {
+        Some(FORM_START) => Some(FORM_END),
+        Some(BRACKET_FORM_START) => Some(BRACKET_FORM_END),
+        Some(BRACE_FORM_START) => Some(BRACE_FORM_END),
+        _ => None,
+    }
+}
+
+pub const MAX_FORM_DEPTH: usize = 128;
+
 pub const EMPTY: &str = "";
 
 pub fn is_empty(s: &str) -> bool {
     s == EMPTY
 }
+
+pub const MAX_SUGGESTIONS: usize = 3;
+
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut prev_row: Vec<usize> = (0..=b_chars.len()).collect();
+
+    for (i, ac) in a.chars().enumerate() {
+        let mut row = vec![i + 1];
+
+        for (j, bc) in b_chars.iter().enumerate() {
+            let cost = if ac == *bc { 0 } else { 1 };
+
+            let value = (prev_row[j] + cost)
+                .min(prev_row[j + 1] + 1)
+                .min(row[j] + 1);
+
+            row.push(value);
+        }
+
+        prev_row = row;
+    }
+
+    prev_row[b_chars.len()]
+}
+
+pub fn suggestions<'a, I>(s: &str, candidates: I) -> Vec<String>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let max_distance = (s.chars().count() / 3).max(1);
+
+    let mut scored: Vec<(usize, String)> = candidates
+        .into_iter()
+        .filter(|&c| c != s)
+        .map(|c| (edit_distance(s, c), c.to_string()))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+
+    scored.sort();
+    scored.dedup();
+
+    scored
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, c)| c)
+        .collect()
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum GrammarExpr {
+    Literal(String),
+    Rule(&'static str),
+    Special(&'static str, &'static str),
+    Seq(Vec<GrammarExpr>),
+    Alt(Vec<GrammarExpr>),
+    Opt(Box<GrammarExpr>),
+    Many(Box<GrammarExpr>),
+}
+
+impl GrammarExpr {
+    pub fn literal<T: fmt::Display>(s: T) -> Self {
+        GrammarExpr::Literal(s.to_string())
+    }
+
+    pub fn opt(expr: GrammarExpr) -> Self {
+        GrammarExpr::Opt(Box::new(expr))
+    }
+
+    pub fn many(expr: GrammarExpr) -> Self {
+        GrammarExpr::Many(Box::new(expr))
+    }
+
+    pub fn many1(expr: GrammarExpr) -> Self {
+        GrammarExpr::Seq(vec![expr.clone(), GrammarExpr::many(expr)])
+    }
+
+    pub fn rules(&self) -> Vec<&'static str> {
+        match self {
+            GrammarExpr::Literal(_) | GrammarExpr::Special(_, _) => vec![],
+            GrammarExpr::Rule(name) => vec![name],
+            GrammarExpr::Seq(exprs) | GrammarExpr::Alt(exprs) => {
+                exprs.iter().flat_map(|e| e.rules()).collect()
+            }
+            GrammarExpr::Opt(expr) | GrammarExpr::Many(expr) => expr.rules(),
+        }
+    }
+
+    pub fn to_ebnf(&self) -> String {
+        match self {
+            GrammarExpr::Literal(s) => {
+                if s.contains(DOUBLE_QUOTE) {
+                    format!("'{}'", s)
+                } else {
+                    format!("\"{}\"", s)
+                }
+            }
+            GrammarExpr::Rule(name) => name.to_string(),
+            GrammarExpr::Special(desc, _) => format!("? {} ?", desc),
+            GrammarExpr::Seq(exprs) => exprs
+                .iter()
+                .map(|e| match e {
+                    GrammarExpr::Alt(_) => format!("( {} )", e.to_ebnf()),
+                    _ => e.to_ebnf(),
+                })
+                .collect::<Vec<String>>()
+                .join(" , "),
+            GrammarExpr::Alt(exprs) => exprs
+                .iter()
+                .map(|e| e.to_ebnf())
+                .collect::<Vec<String>>()
+                .join(" | "),
+            GrammarExpr::Opt(expr) => format!("[ {} ]", expr.to_ebnf()),
+            GrammarExpr::Many(expr) => format!("{{ {} }}", expr.to_ebnf()),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct GrammarRule {
+    pub name: &'static str,
+    pub expr: GrammarExpr,
+    pub lexical: bool,
+}
+
+impl GrammarRule {
+    pub fn new(name: &'static str, expr: GrammarExpr) -> Self {
+        GrammarRule {
+            name,
+            expr,
+            lexical: false,
+        }
+    }
+
+    pub fn token(name: &'static str, expr: GrammarExpr) -> Self {
+        GrammarRule {
+            name,
+            expr,
+            lexical: true,
+        }
+    }
+
+    pub fn to_ebnf(&self) -> String {
+        format!("{} = {} ;", self.name, self.expr.to_ebnf())
+    }
+}
+
+pub fn grammar() -> Vec<GrammarRule> {
+    use GrammarExpr::{Alt, Rule, Seq, Special};
+
+    let doc_comment_mark = format!("{}{}", COMMENT_MARK, COMMENT_MARK_POSTFIX);
+    let sign = || Alt(vec![GrammarExpr::literal('+'), GrammarExpr::literal('-')]);
+    let line_rest = || {
+        Seq(vec![
+            GrammarExpr::many(Special("any character except newline", "[^\\n]")),
+            GrammarExpr::opt(Special("newline", "\\n")),
+        ])
+    };
+    let delimited = |start: char, end: char| {
+        Seq(vec![
+            GrammarExpr::literal(start),
+            Rule("head"),
+            GrammarExpr::many(Rule("element")),
+            GrammarExpr::literal(end),
+        ])
+    };
+
+    vec![
+        GrammarRule::new(
+            "file",
+            Seq(vec![
+                GrammarExpr::opt(Rule("shebang")),
+                GrammarExpr::many(Rule("element")),
+            ]),
+        ),
+        GrammarRule::new(
+            "element",
+            Alt(vec![Rule("comment"), Rule("doc-comment"), Rule("value")]),
+        ),
+        GrammarRule::new("value", Alt(vec![Rule("form"), Rule("atom")])),
+        GrammarRule::new(
+            "form",
+            Alt(vec![
+                delimited(FORM_START, FORM_END),
+                delimited(BRACKET_FORM_START, BRACKET_FORM_END),
+                delimited(BRACE_FORM_START, BRACE_FORM_END),
+            ]),
+        ),
+        GrammarRule::new("head", Alt(vec![Rule("keyword"), Rule("symbol")])),
+        GrammarRule::new(
+            "atom",
+            Alt(vec![
+                Rule("empty"),
+                Rule("uint"),
+                Rule("int"),
+                Rule("float"),
+                Rule("char"),
+                Rule("string"),
+                Rule("keyword"),
+                Rule("symbol"),
+            ]),
+        ),
+        GrammarRule::token(
+            "empty",
+            Seq(vec![
+                GrammarExpr::literal(FORM_START),
+                GrammarExpr::literal(FORM_END),
+            ]),
+        ),
+        GrammarRule::token(
+            "shebang",
+            Seq(vec![
+                GrammarExpr::literal(&doc_comment_mark),
+                GrammarExpr::literal(SHEBANG_PATH_START),
+                line_rest(),
+            ]),
+        ),
+        GrammarRule::token(
+            "comment",
+            Seq(vec![GrammarExpr::literal(COMMENT_MARK), line_rest()]),
+        ),
+        GrammarRule::token(
+            "doc-comment",
+            Seq(vec![GrammarExpr::literal(&doc_comment_mark), line_rest()]),
+        ),
+        GrammarRule::token(
+            "keyword",
+            Alt(KEYWORDS.iter().map(GrammarExpr::literal).collect()),
+        ),
+        GrammarRule::token(
+            "uint",
+            Alt(vec![
+                GrammarExpr::many1(Special("decimal digit", "[0-9]")),
+                Seq(vec![
+                    GrammarExpr::literal('b'),
+                    GrammarExpr::many1(Special("binary digit", "[01]")),
+                ]),
+                Seq(vec![
+                    GrammarExpr::literal('o'),
+                    GrammarExpr::many1(Special("octal digit", "[0-7]")),
+                ]),
+                Seq(vec![
+                    GrammarExpr::literal('x'),
+                    GrammarExpr::many1(Special("a to f", "[a-f]")),
+                ]),
+                Seq(vec![
+                    GrammarExpr::literal('X'),
+                    GrammarExpr::many1(Special("decimal digit or A to F", "[0-9A-F]")),
+                ]),
+            ]),
+        ),
+        GrammarRule::token("int", Seq(vec![sign(), Rule("uint")])),
+        GrammarRule::token(
+            "float",
+            Seq(vec![
+                GrammarExpr::opt(sign()),
+                GrammarExpr::many1(Special("decimal digit", "[0-9]")),
+                GrammarExpr::literal(SYMBOL_PATH_SEPARATOR),
+                GrammarExpr::many1(Special("decimal digit", "[0-9]")),
+                GrammarExpr::opt(Seq(vec![
+                    GrammarExpr::literal('E'),
+                    GrammarExpr::opt(sign()),
+                    GrammarExpr::many1(Special("decimal digit", "[0-9]")),
+                ])),
+            ]),
+        ),
+        GrammarRule::token(
+            "char",
+            Seq(vec![
+                GrammarExpr::literal(SINGLE_QUOTE),
+                Alt(vec![
+                    Seq(vec![
+                        GrammarExpr::literal(ESCAPE_CHAR),
+                        Special("any character", "."),
+                    ]),
+                    Special("any character", "."),
+                ]),
+                GrammarExpr::literal(SINGLE_QUOTE),
+            ]),
+        ),
+        GrammarRule::token(
+            "string",
+            Seq(vec![
+                GrammarExpr::literal(DOUBLE_QUOTE),
+                GrammarExpr::many(Alt(vec![
+                    Seq(vec![
+                        GrammarExpr::literal(ESCAPE_CHAR),
+                        Special("any character", "."),
+                    ]),
+                    Special("any character except double quote or escape", "[^\"\\\\]"),
+                ])),
+                GrammarExpr::literal(DOUBLE_QUOTE),
+            ]),
+        ),
+        GrammarRule::new(
+            "symbol",
+            Alt(vec![
+                Rule("value-symbol"),
+                Rule("type-symbol"),
+                Rule("value-path-symbol"),
+                Rule("type-path-symbol"),
+            ]),
+        ),
+        GrammarRule::token(
+            "value-symbol",
+            Alt(vec![
+                Seq(vec![
+                    Special("a to z", "[a-z]"),
+                    GrammarExpr::many(Special("ASCII letter or digit", "[A-Za-z0-9]")),
+                    GrammarExpr::opt(Rule("punctuation")),
+                ]),
+                Seq(vec![
+                    Rule("punctuation"),
+                    GrammarExpr::opt(Seq(vec![
+                        Rule("punctuation"),
+                        GrammarExpr::opt(Rule("punctuation")),
+                    ])),
+                ]),
+            ]),
+        ),
+        GrammarRule::token(
+            "type-symbol",
+            Seq(vec![
+                Special("A to Z", "[A-Z]"),
+                GrammarExpr::many(Special("ASCII letter or digit", "[A-Za-z0-9]")),
+            ]),
+        ),
+        GrammarRule::token(
+            "path",
+            GrammarExpr::many1(Seq(vec![
+                Special("a to z", "[a-z]"),
+                GrammarExpr::many(Special("ASCII letter", "[A-Za-z]")),
+                GrammarExpr::literal(SYMBOL_PATH_SEPARATOR),
+            ])),
+        ),
+        GrammarRule::token(
+            "value-path-symbol",
+            Seq(vec![Rule("path"), Rule("value-symbol")]),
+        ),
+        GrammarRule::token(
+            "type-path-symbol",
+            Seq(vec![Rule("path"), Rule("type-symbol")]),
+        ),
+        GrammarRule::token(
+            "punctuation",
+            Alt(SYMBOL_START_PUNCTUATION
+                .iter()
+                .filter(|&&c| !is_symbol_path_separator(c))
+                .map(GrammarExpr::literal)
+                .collect()),
+        ),
+    ]
+}
+
+pub fn grammar_to_ebnf() -> String {
+    grammar()
+        .iter()
+        .map(|rule| rule.to_ebnf())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub const TREE_SITTER_INDENT: &str = "  ";
+
+fn tree_sitter_name(name: &str) -> String {
+    name.replace('-', "_")
+}
+
+fn tree_sitter_string(s: &str) -> String {
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+fn tree_sitter_expr(expr: &GrammarExpr, rules: &[GrammarRule], inline: bool) -> String {
+    let list = |exprs: &[GrammarExpr]| {
+        exprs
+            .iter()
+            .map(|e| tree_sitter_expr(e, rules, inline))
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+
+    match expr {
+        GrammarExpr::Literal(s) => tree_sitter_string(s),
+        GrammarExpr::Special(_, pattern) => format!("/{}/", pattern),
+        GrammarExpr::Rule(name) => match rules.iter().find(|r| &r.name == name) {
+            Some(rule) if inline => tree_sitter_expr(&rule.expr, rules, inline),
+            _ => format!("$.{}", tree_sitter_name(name)),
+        },
+        GrammarExpr::Seq(exprs) => {
+            let mut flat = vec![];
+
+            for e in exprs.iter() {
+                match e {
+                    GrammarExpr::Seq(inner) => flat.extend(inner.iter().cloned()),
+                    _ => flat.push(e.clone()),
+                }
+            }
+
+            if flat.len() == 1 {
+                tree_sitter_expr(&flat[0], rules, inline)
+            } else {
+                format!("seq({})", list(&flat))
+            }
+        }
+        GrammarExpr::Alt(exprs) => format!("choice({})", list(exprs)),
+        GrammarExpr::Opt(expr) => format!("optional({})", tree_sitter_expr(expr, rules, inline)),
+        GrammarExpr::Many(expr) => format!("repeat({})", tree_sitter_expr(expr, rules, inline)),
+    }
+}
+
+fn literal_prefix_len(expr: &GrammarExpr) -> usize {
+    match expr {
+        GrammarExpr::Literal(s) => s.len(),
+        GrammarExpr::Seq(exprs) => exprs
+            .iter()
+            .take_while(|e| matches!(e, GrammarExpr::Literal(_)))
+            .map(literal_prefix_len)
+            .sum(),
+        _ => 0,
+    }
+}
+
+fn tree_sitter_rules(rules: &[GrammarRule]) -> Vec<&GrammarRule> {
+    rules
+        .iter()
+        .filter(|rule| {
+            !rule.lexical
+                || rules
+                    .iter()
+                    .filter(|r| !r.lexical)
+                    .any(|r| r.expr.rules().contains(&rule.name))
+        })
+        .collect()
+}
+
+pub fn grammar_to_tree_sitter(language: &str) -> String {
+    let rules = grammar();
+    let indent = TREE_SITTER_INDENT;
+
+    let mut lines = vec![
+        "module.exports = grammar({".to_string(),
+        format!("{}name: {},", indent, tree_sitter_string(language)),
+        String::new(),
+        format!("{}extras: $ => [/\\s/],", indent),
+        String::new(),
+        format!("{}rules: {{", indent),
+    ];
+
+    for rule in tree_sitter_rules(&rules) {
+        let expr = if !rule.lexical {
+            tree_sitter_expr(&rule.expr, &rules, false)
+        } else {
+            let expr = tree_sitter_expr(&rule.expr, &rules, true);
+
+            match &rule.expr {
+                GrammarExpr::Alt(exprs)
+                    if exprs.iter().all(|e| matches!(e, GrammarExpr::Literal(_))) =>
+                {
+                    expr
+                }
+                _ => match literal_prefix_len(&rule.expr) {
+                    0 => format!("token({})", expr),
+                    prec => format!("token(prec({}, {}))", prec, expr),
+                },
+            }
+        };
+
+        lines.push(format!(
+            "{}{}: $ => {},",
+            indent.repeat(2),
+            tree_sitter_name(rule.name),
+            expr
+        ));
+    }
+
+    lines.push(format!("{}}},", indent));
+    lines.push("});".into());
+
+    lines.join("\n") + "\n"
+}
+
+fn highlight_capture(name: &str) -> Option<&'static str> {
+    match name {
+        "shebang" => Some("keyword.directive"),
+        "comment" => Some("comment"),
+        "doc-comment" => Some("comment.documentation"),
+        "keyword" => Some("keyword"),
+        "empty" => Some("constant.builtin"),
+        "uint" | "int" | "float" => Some("number"),
+        "char" => Some("character"),
+        "string" => Some("string"),
+        "type-symbol" | "type-path-symbol" => Some("type"),
+        "value-symbol" | "value-path-symbol" => Some("variable"),
+        _ => None,
+    }
+}
+
+pub fn tree_sitter_highlights() -> String {
+    let rules = grammar();
+
+    let mut lines = vec![
+        "(head (symbol (value_symbol) @function.call))".to_string(),
+        "(head (symbol (value_path_symbol) @function.call))".into(),
+    ];
+
+    for rule in tree_sitter_rules(&rules) {
+        if let Some(capture) = highlight_capture(rule.name) {
+            lines.push(format!("({}) @{}", tree_sitter_name(rule.name), capture));
+        }
+    }
+
+    let brackets = [
+        FORM_START,
+        FORM_END,
+        BRACKET_FORM_START,
+        BRACKET_FORM_END,
+        BRACE_FORM_START,
+        BRACE_FORM_END,
+    ];
+
+    lines.push(format!(
+        "[{}] @punctuation.bracket",
+        brackets
+            .iter()
+            .map(|c| format!("\"{}\"", c))
+            .collect::<Vec<String>>()
+            .join(" ")
+    ));
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn symbol_suggestions() {
+        use super::{edit_distance, suggestions};
+
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("printf", "println"), 2);
+        assert_eq!(edit_distance("abc", ""), 3);
+
+        let candidates = vec!["printf", "println", "print", "exit", "printChar"];
+
+        assert_eq!(
+            suggestions("prinf", candidates.clone()),
+            vec!["print".to_string(), "printf".into()]
+        );
+        assert_eq!(
+            suggestions("printl", candidates.clone()),
+            vec!["print".to_string(), "printf".into(), "println".into()]
+        );
+        assert!(suggestions("unwrap", candidates).is_empty());
+    }
+
+    #[test]
+    fn keyword_error_suggestions() {
+        use super::Keyword;
+        use crate::error::{Error, KeywordError};
+        use crate::token::Tokens;
+
+        assert_eq!(Keyword::from_str("vec").unwrap(), Keyword::Vec);
+
+        let tokens = Tokens::from_str("(vall x 0)").unwrap();
+
+        match Keyword::from_token(&tokens[1]) {
+            Err(Error::Keyword(KeywordError {
+                loc,
+                word,
+                suggestions,
+            })) => {
+                assert_eq!(loc.unwrap().pos, 1);
+                assert_eq!(word, "vall".to_string());
+                assert_eq!(suggestions, vec!["val".to_string()]);
+            }
+            _ => panic!("invalid branch"),
+        }
+
+        let err = Keyword::from_str("imprt").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "syntactic error: unknown keyword imprt, did you mean import?".to_string()
+        );
+
+        let err = Keyword::from_str("zzzzzz").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "syntactic error: unknown keyword zzzzzz".to_string()
+        );
+    }
+
+    #[test]
+    fn syntax_config() {
+        use super::SyntaxConfig;
+
+        let mut config = SyntaxConfig::new();
+
+        assert!(config.is_keyword("val"));
+        assert!(config.is_keyword("Fun"));
+        assert!(config.is_type_keyword("Fun"));
+        assert!(!config.is_type_keyword("val"));
+
+        assert!(config.check_definition_name("val", None).is_err());
+        assert!(config.check_definition_name("print", None).is_ok());
+
+        assert!(config.register_builtin("print").is_ok());
+        assert!(config.register_builtin("print").is_err());
+        assert!(config.register_builtin("(").is_err());
+        assert!(config.check_definition_name("print", None).is_err());
+
+        assert!(config.register_reserved_symbol("async").is_ok());
+        assert!(config.check_definition_name("async", None).is_err());
+    }
+
+    #[test]
+    fn grammar_ebnf() {
+        use super::{grammar, grammar_to_ebnf, KEYWORDS};
+
+        let rules = grammar();
+
+        for rule in rules.iter() {
+            assert_eq!(rules.iter().filter(|r| r.name == rule.name).count(), 1);
+
+            for name in rule.expr.rules() {
+                assert!(
+                    rules.iter().any(|r| r.name == name),
+                    "undefined rule {}",
+                    name
+                );
+            }
+        }
+
+        let ebnf = grammar_to_ebnf();
+        let lines: Vec<&str> = ebnf.lines().collect();
+
+        assert_eq!(lines.len(), rules.len());
+        assert_eq!(lines[0], "file = [ shebang ] , { element } ;");
+        assert!(lines.contains(&"empty = \"(\" , \")\" ;"));
+        assert!(lines.contains(&"int = ( \"+\" | \"-\" ) , uint ;"));
+        assert!(lines.contains(
+            &"comment = \"#\" , { ? any character except newline ? } , [ ? newline ? ] ;"
+        ));
+        assert!(lines.contains(
+            &"form = \"(\" , head , { element } , \")\" | \"[\" , head , { element } , \"]\" | \"{\" , head , { element } , \"}\" ;"
+        ));
+        assert!(lines.contains(&"string = '\"' , { \"\\\" , ? any character ? | ? any character except double quote or escape ? } , '\"' ;"));
+
+        let keyword_rule = rules.iter().find(|r| r.name == "keyword").unwrap();
+        let keyword_ebnf = keyword_rule.expr.to_ebnf();
+
+        for keyword in KEYWORDS.iter() {
+            assert!(keyword_ebnf.contains(&format!("\"{}\"", keyword)));
+        }
+    }
+
+    #[test]
+    fn grammar_tree_sitter() {
+        use super::{grammar_to_tree_sitter, tree_sitter_highlights};
+        use crate::snapshot::assert_file_snapshot;
+        use std::path::Path;
+
+        let grammar_js = grammar_to_tree_sitter("aster");
+        let lines: Vec<&str> = grammar_js.lines().collect();
+
+        assert!(lines.contains(&"    file: $ => seq(optional($.shebang), repeat($.element)),"));
+        assert!(lines.contains(&"    empty: $ => token(prec(2, seq('(', ')'))),"));
+        assert!(lines.contains(
+            &"    comment: $ => token(prec(1, seq('#', repeat(/[^\\n]/), optional(/\\n/)))),"
+        ));
+        assert!(lines
+            .iter()
+            .any(|l| l.starts_with("    keyword: $ => choice('module', ")));
+        assert!(!lines.iter().any(|l| l.starts_with("    path: ")));
+        assert!(!lines.iter().any(|l| l.starts_with("    punctuation: ")));
+
+        let highlights = tree_sitter_highlights();
+
+        assert!(highlights
+            .lines()
+            .any(|l| l == "(doc_comment) @comment.documentation"));
+        assert!(highlights.lines().any(|l| l == "(type_path_symbol) @type"));
+
+        assert_file_snapshot(Path::new("tree-sitter/grammar.js"), &grammar_js);
+        assert_file_snapshot(Path::new("tree-sitter/queries/highlights.scm"), &highlights);
+    }
+}
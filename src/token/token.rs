@@ -3,10 +3,12 @@ use crate::loc::Loc;
 use crate::syntax::EMPTY;
 use std::fmt;
 
-#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Default)]
 pub enum TokenKind {
+    #[default]
     Comment,
     DocComment,
+    Shebang,
     Keyword,
     EmptyLiteral,
     UIntLiteral,
@@ -20,11 +22,38 @@ pub enum TokenKind {
     TypePathSymbol,
     FormStart,
     FormEnd,
+    BracketFormStart,
+    BracketFormEnd,
+    BraceFormStart,
+    BraceFormEnd,
 }
 
-impl Default for TokenKind {
-    fn default() -> Self {
-        TokenKind::Comment
+impl TokenKind {
+    pub fn is_comment(&self) -> bool {
+        matches!(self, TokenKind::Comment | TokenKind::DocComment)
+    }
+
+    pub fn is_form_start(&self) -> bool {
+        matches!(
+            self,
+            TokenKind::FormStart | TokenKind::BracketFormStart | TokenKind::BraceFormStart
+        )
+    }
+
+    pub fn is_form_end(&self) -> bool {
+        matches!(
+            self,
+            TokenKind::FormEnd | TokenKind::BracketFormEnd | TokenKind::BraceFormEnd
+        )
+    }
+
+    pub fn form_end(&self) -> Option<TokenKind> {
+        match self {
+            TokenKind::FormStart => Some(TokenKind::FormEnd),
+            TokenKind::BracketFormStart => Some(TokenKind::BracketFormEnd),
+            TokenKind::BraceFormStart => Some(TokenKind::BraceFormEnd),
+            _ => None,
+        }
     }
 }
 
@@ -54,6 +83,10 @@ impl Token {
         Token::new_from_kind(TokenKind::DocComment)
     }
 
+    pub fn new_shebang() -> Self {
+        Token::new_from_kind(TokenKind::Shebang)
+    }
+
     pub fn new_keyword() -> Self {
         Token::new_from_kind(TokenKind::Keyword)
     }
@@ -106,6 +139,33 @@ impl Token {
         Token::new_from_kind(TokenKind::FormEnd)
     }
 
+    pub fn new_bracket_form_start() -> Self {
+        Token::new_from_kind(TokenKind::BracketFormStart)
+    }
+
+    pub fn new_bracket_form_end() -> Self {
+        Token::new_from_kind(TokenKind::BracketFormEnd)
+    }
+
+    pub fn new_brace_form_start() -> Self {
+        Token::new_from_kind(TokenKind::BraceFormStart)
+    }
+
+    pub fn new_brace_form_end() -> Self {
+        Token::new_from_kind(TokenKind::BraceFormEnd)
+    }
+
+    pub fn synthetic(kind: TokenKind, content: &str, origin: Loc) -> Self {
+        let mut token = Token::new_from_kind(kind);
+
+        token.push(StringChunk {
+            loc: Loc::synthetic(origin),
+            content: content.into(),
+        });
+
+        token
+    }
+
     pub fn file(&self) -> String {
         if !self.chunks.files.is_empty() {
             self.chunks.files[0].clone()
@@ -1,16 +1,21 @@
 use crate::chunk::StringChunks;
 use crate::error::{Error, SyntacticError};
+use crate::loc::Loc;
 use crate::result::Result;
 use crate::syntax::is_keyword;
+use crate::syntax::MAX_FORM_DEPTH;
 use crate::syntax::SINGLE_QUOTE;
-use crate::syntax::{is_comment_mark, is_doc_comment_mark};
+use crate::syntax::{form_end_of, is_any_form_end, is_any_form_start};
+use crate::syntax::{is_bracket_form_end, is_bracket_form_start};
+use crate::syntax::{is_comment_mark, is_doc_comment_mark, is_shebang};
 use crate::syntax::{is_double_quote, is_single_quote};
 use crate::syntax::{is_escape_char, is_whitespace};
 use crate::syntax::{is_float_literal, is_int_literal, is_uint_literal};
 use crate::syntax::{is_form_end, is_form_start};
 use crate::syntax::{is_symbol, is_type_symbol, is_value_symbol};
 use crate::syntax::{is_type_path_symbol, is_value_path_symbol};
-use crate::token::Token;
+use crate::syntax::{COMMENT_MARK, COMMENT_MARK_POSTFIX};
+use crate::token::{Token, TokenKind};
 use std::convert;
 use std::fmt;
 use std::fs;
@@ -40,13 +45,26 @@ impl Tokens {
 
     #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Result<Self> {
-        let chunks = StringChunks::from_str(s);
+        Self::from_chunks(StringChunks::from_str(s))
+    }
+
+    pub fn from_str_with_origin(s: &str, origin: Option<Loc>) -> Result<Self> {
+        Self::from_chunks(StringChunks::from_str_with_origin(s, origin))
+    }
+
+    pub fn from_str_with_depth_limit(s: &str, depth_limit: usize) -> Result<Self> {
+        Self::from_chunks_with_depth_limit(StringChunks::from_str(s), depth_limit)
+    }
+
+    pub fn from_chunks(chunks: StringChunks) -> Result<Self> {
+        Self::from_chunks_with_depth_limit(chunks, MAX_FORM_DEPTH)
+    }
+
+    pub fn from_chunks_with_depth_limit(chunks: StringChunks, depth_limit: usize) -> Result<Self> {
         let len = chunks.len();
         let mut idx = 0;
 
-        let mut forms_count = 0;
-        let mut open_form_idxs = vec![idx];
-        let mut close_form_idxs = vec![];
+        let mut open_form_idxs: Vec<usize> = vec![];
 
         let mut tokens = Tokens::new();
 
@@ -90,6 +108,14 @@ impl Tokens {
                         x = chunks[idx].content.clone();
                     }
 
+                    if tokens.is_empty()
+                        && cchunk.loc.line == 0
+                        && cchunk.loc.pos == 0
+                        && is_shebang(&cchunk.content)
+                    {
+                        token = Token::new_shebang();
+                    }
+
                     token.push(cchunk.clone());
                     tokens.push(token);
 
@@ -217,19 +243,21 @@ impl Tokens {
 
                     idx += 1;
                 }
-                mut x if is_form_start(&x) => {
-                    forms_count += 1;
-                    open_form_idxs.push(idx);
+                mut x if is_any_form_start(&x) => {
                     let mut is_empty = false;
 
-                    if idx + 1 < len && is_form_end(&chunks[idx + 1].content) {
+                    if idx + 1 < len && is_form_start(&x) && is_form_end(&chunks[idx + 1].content) {
                         is_empty = true;
                     }
 
                     let mut token = if is_empty {
                         Token::new_empty_literal()
-                    } else {
+                    } else if is_form_start(&x) {
                         Token::new_form_start()
+                    } else if is_bracket_form_start(&x) {
+                        Token::new_bracket_form_start()
+                    } else {
+                        Token::new_brace_form_start()
                     };
 
                     if is_empty {
@@ -237,10 +265,6 @@ impl Tokens {
 
                         idx += 1;
 
-                        forms_count -= 1;
-                        open_form_idxs.pop();
-                        close_form_idxs.push(idx);
-
                         x = chunks[idx].content.clone();
                         fchunk.content.push_str(&x);
 
@@ -249,25 +273,50 @@ impl Tokens {
 
                         idx += 1;
                     } else {
+                        if open_form_idxs.len() >= depth_limit {
+                            return Err(Error::Syntactic(SyntacticError {
+                                loc: Some(chunk.loc),
+                                desc: format!("form nesting exceeds depth limit {}", depth_limit),
+                            }));
+                        }
+
+                        open_form_idxs.push(idx);
+
                         token.push(chunk.clone());
                         tokens.push(token);
                         idx += 1;
                     }
                 }
-                x if is_form_end(&x) => {
-                    forms_count -= 1;
+                x if is_any_form_end(&x) => {
+                    let open_idx = match open_form_idxs.pop() {
+                        Some(open_idx) => open_idx,
+                        None => {
+                            return Err(Error::Syntactic(SyntacticError {
+                                loc: Some(chunks[idx].loc.clone()),
+                                desc: "closing a form never opened".into(),
+                            }));
+                        }
+                    };
 
-                    if forms_count < 0 {
+                    let expected = form_end_of(&chunks[open_idx].content).unwrap();
+
+                    if x != expected.to_string() {
                         return Err(Error::Syntactic(SyntacticError {
                             loc: Some(chunks[idx].loc.clone()),
-                            desc: "closing a form never opened".into(),
+                            desc: format!(
+                                "expected {} to close the form opened at line {}, pos {}, found {}",
+                                expected, chunks[open_idx].loc.line, chunks[open_idx].loc.pos, x
+                            ),
                         }));
                     }
 
-                    open_form_idxs.pop();
-                    close_form_idxs.push(idx);
-
-                    let mut token = Token::new_form_end();
+                    let mut token = if is_form_end(&x) {
+                        Token::new_form_end()
+                    } else if is_bracket_form_end(&x) {
+                        Token::new_bracket_form_end()
+                    } else {
+                        Token::new_brace_form_end()
+                    };
                     token.push(chunk.clone());
 
                     tokens.push(token);
@@ -305,25 +354,61 @@ impl Tokens {
             }
         }
 
-        if forms_count != 0 {
-            let (err_idx, desc): (usize, String) = if forms_count > 0 {
-                (open_form_idxs.remove(0), "form not closed".into())
-            } else {
-                (
-                    close_form_idxs.pop().unwrap(),
-                    "closing a form never opened".into(),
-                )
-            };
-
+        if let Some(&err_idx) = open_form_idxs.first() {
             return Err(Error::Syntactic(SyntacticError {
                 loc: Some(chunks[err_idx].loc.clone()),
-                desc,
+                desc: "form not closed".into(),
             }));
         }
 
         Ok(tokens)
     }
 
+    pub fn doc_comments_before(&self, loc: &Loc) -> Tokens {
+        let mut docs = Tokens::new();
+
+        let idx = match self.0.iter().position(|t| t.loc().as_ref() == Some(loc)) {
+            Some(idx) => idx,
+            None => return docs,
+        };
+
+        let mut line = loc.line;
+
+        for token in self.0[..idx].iter().rev() {
+            if token.kind != TokenKind::DocComment {
+                break;
+            }
+
+            let token_line = token.loc().map(|l| l.line).unwrap_or_default();
+
+            if token_line + 1 != line {
+                break;
+            }
+
+            line = token_line;
+            docs.push(token.clone());
+        }
+
+        docs.0.reverse();
+
+        docs
+    }
+
+    pub fn doc_comments_to_string(&self) -> String {
+        let mark = format!("{}{}", COMMENT_MARK, COMMENT_MARK_POSTFIX);
+
+        self.0
+            .iter()
+            .filter(|t| t.kind == TokenKind::DocComment)
+            .map(|t| {
+                let s = t.to_string();
+                let s = s.trim_end_matches('\n').trim_start_matches(&mark);
+                s.strip_prefix(' ').unwrap_or(s).to_string()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
     pub fn from_string(s: String) -> Result<Self> {
         Self::from_str(&s)
     }
@@ -428,6 +513,65 @@ mod tests {
         assert_eq!(tokens[2].kind, TokenKind::DocComment);
     }
 
+    #[test]
+    fn shebang_tokens() {
+        use super::Tokens;
+        use crate::token::TokenKind;
+
+        let mut s = "#!/usr/bin/env aster\n#! this is a doc comment\n(f a)";
+
+        let mut tokens = Tokens::from_str(s).unwrap();
+
+        assert_eq!(tokens.len(), 6);
+        assert_eq!(tokens[0].kind, TokenKind::Shebang);
+        assert_eq!(tokens[0].to_string(), "#!/usr/bin/env aster\n".to_string());
+        assert_eq!(tokens[1].kind, TokenKind::DocComment);
+
+        s = "\n#!/usr/bin/env aster\n";
+
+        tokens = Tokens::from_str(s).unwrap();
+
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].kind, TokenKind::DocComment);
+
+        s = "#é comment\n(f a)";
+
+        tokens = Tokens::from_str(s).unwrap();
+
+        assert_eq!(tokens.len(), 5);
+        assert_eq!(tokens[0].kind, TokenKind::Comment);
+    }
+
+    #[test]
+    fn synthetic_tokens() {
+        use super::Tokens;
+        use crate::loc::Loc;
+        use crate::token::{Token, TokenKind};
+
+        let origin = Loc {
+            file: Some("gen.at".into()),
+            line: 10,
+            pos: 2,
+            origin: None,
+        };
+
+        let tokens = Tokens::from_str_with_origin("(f a\n b)", Some(origin.clone())).unwrap();
+
+        assert_eq!(tokens.len(), 5);
+
+        let loc = tokens[3].loc().unwrap();
+
+        assert!(loc.is_synthetic());
+        assert_eq!(loc.line, 1);
+        assert_eq!(loc.pos, 1);
+        assert_eq!(loc.source_loc(), origin);
+
+        let token = Token::synthetic(TokenKind::ValueSymbol, "g", origin.clone());
+
+        assert_eq!(token.to_string(), "g".to_string());
+        assert_eq!(token.loc().unwrap().source_loc(), origin);
+    }
+
     #[test]
     fn keyword_tokens() {
         use super::Tokens;
@@ -647,6 +791,102 @@ mod tests {
         assert_eq!(tokens[17].kind, TokenKind::Comment);
     }
 
+    #[test]
+    fn unbalanced_forms_tokens() {
+        use super::Tokens;
+        use crate::error::{Error, SyntacticError};
+
+        let mut res = Tokens::from_str("(a b)\n  (c (d e)\n(f)");
+
+        match res {
+            Err(Error::Syntactic(SyntacticError { loc, desc })) => {
+                let loc = loc.unwrap();
+                assert_eq!(loc.line, 1);
+                assert_eq!(loc.pos, 2);
+                assert_eq!(desc, "form not closed".to_string());
+            }
+            _ => panic!("invalid branch"),
+        }
+
+        res = Tokens::from_str("(a b))\n(c)");
+
+        match res {
+            Err(Error::Syntactic(SyntacticError { loc, desc })) => {
+                let loc = loc.unwrap();
+                assert_eq!(loc.line, 0);
+                assert_eq!(loc.pos, 5);
+                assert_eq!(desc, "closing a form never opened".to_string());
+            }
+            _ => panic!("invalid branch"),
+        }
+    }
+
+    #[test]
+    fn deeply_nested_forms_tokens() {
+        use super::Tokens;
+        use crate::error::{Error, SyntacticError};
+        use crate::syntax::MAX_FORM_DEPTH;
+
+        let nested = |depth: usize| format!("{}a{}", "(f ".repeat(depth), ")".repeat(depth));
+
+        let res = Tokens::from_str(&nested(MAX_FORM_DEPTH));
+
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap().len(), MAX_FORM_DEPTH * 3 + 1);
+
+        let res = Tokens::from_str(&nested(MAX_FORM_DEPTH + 1));
+
+        match res {
+            Err(Error::Syntactic(SyntacticError { loc, desc })) => {
+                assert_eq!(loc.unwrap().pos, MAX_FORM_DEPTH * 3);
+                assert_eq!(
+                    desc,
+                    format!("form nesting exceeds depth limit {}", MAX_FORM_DEPTH)
+                );
+            }
+            _ => panic!("invalid branch"),
+        }
+
+        assert!(Tokens::from_str_with_depth_limit(&nested(3), 3).is_ok());
+        assert!(Tokens::from_str_with_depth_limit(&nested(4), 3).is_err());
+    }
+
+    #[test]
+    fn delimited_forms_tokens() {
+        use super::Tokens;
+        use crate::error::{Error, SyntacticError};
+        use crate::token::TokenKind;
+
+        let mut res = Tokens::from_str("[f {g a} (h) []]");
+
+        assert!(res.is_ok());
+
+        let tokens = res.unwrap();
+
+        assert_eq!(tokens.len(), 12);
+        assert_eq!(tokens[0].kind, TokenKind::BracketFormStart);
+        assert_eq!(tokens[2].kind, TokenKind::BraceFormStart);
+        assert_eq!(tokens[5].kind, TokenKind::BraceFormEnd);
+        assert_eq!(tokens[6].kind, TokenKind::FormStart);
+        assert_eq!(tokens[8].kind, TokenKind::FormEnd);
+        assert_eq!(tokens[9].kind, TokenKind::BracketFormStart);
+        assert_eq!(tokens[10].kind, TokenKind::BracketFormEnd);
+        assert_eq!(tokens[11].kind, TokenKind::BracketFormEnd);
+
+        res = Tokens::from_str("(f {g a)}");
+
+        match res {
+            Err(Error::Syntactic(SyntacticError { loc, desc })) => {
+                assert_eq!(loc.unwrap().pos, 7);
+                assert_eq!(
+                    desc,
+                    "expected } to close the form opened at line 0, pos 3, found )".to_string()
+                );
+            }
+            _ => panic!("invalid branch"),
+        }
+    }
+
     #[test]
     fn tokens_from_file() {
         use super::Tokens;
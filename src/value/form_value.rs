@@ -126,6 +126,58 @@ impl FormValue {
         }
     }
 
+    pub fn kind_name(&self) -> &'static str {
+        match self {
+            FormValue::ModuleForm(_) => "ModuleForm",
+            FormValue::BlockForm(_) => "BlockForm",
+            FormValue::ImportForm(_) => "ImportForm",
+            FormValue::ExportForm(_) => "ExportForm",
+            FormValue::AttrsForm(_) => "AttrsForm",
+            FormValue::TypeForm(_) => "TypeForm",
+            FormValue::SigForm(_) => "SigForm",
+            FormValue::ValForm(_) => "ValForm",
+            FormValue::FunForm(_) => "FunForm",
+            FormValue::LetForm(_) => "LetForm",
+            FormValue::CaseForm(_) => "CaseForm",
+            FormValue::AppForm(_) => "AppForm",
+            FormValue::MapForm(_) => "MapForm",
+            FormValue::VecForm(_) => "VecForm",
+            FormValue::ArrForm(_) => "ArrForm",
+            FormValue::ListForm(_) => "ListForm",
+            FormValue::PairForm(_) => "PairForm",
+            FormValue::Type(_) => "Type",
+        }
+    }
+
+    pub fn classify(form: &Form) -> Vec<&'static str> {
+        let checks: [(&'static str, bool); 18] = [
+            ("ModuleForm", ModuleForm::from_form(form).is_ok()),
+            ("BlockForm", BlockForm::from_form(form).is_ok()),
+            ("ImportForm", ImportForm::from_form(form).is_ok()),
+            ("ExportForm", ExportForm::from_form(form).is_ok()),
+            ("AttrsForm", AttrsForm::from_form(form).is_ok()),
+            ("TypeForm", TypeForm::from_form(form).is_ok()),
+            ("SigForm", SigForm::from_form(form).is_ok()),
+            ("ValForm", ValForm::from_form(form).is_ok()),
+            ("FunForm", FunForm::from_form(form).is_ok()),
+            ("LetForm", LetForm::from_form(form).is_ok()),
+            ("CaseForm", CaseForm::from_form(form).is_ok()),
+            ("AppForm", AppForm::from_form(form).is_ok()),
+            ("MapForm", MapForm::from_form(form).is_ok()),
+            ("VecForm", VecForm::from_form(form).is_ok()),
+            ("ArrForm", ArrForm::from_form(form).is_ok()),
+            ("ListForm", ListForm::from_form(form).is_ok()),
+            ("PairForm", PairForm::from_form(form).is_ok()),
+            ("Type", Type::from_form(form).is_ok()),
+        ];
+
+        checks
+            .iter()
+            .filter(|(_, ok)| *ok)
+            .map(|(name, _)| *name)
+            .collect()
+    }
+
     #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Result<FormValue> {
         let tokens = Tokens::from_str(s)?;
@@ -200,3 +252,48 @@ impl std::str::FromStr for FormValue {
         Self::from_str(s)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn form_value_classification() {
+        use super::FormValue;
+        use crate::value::forms::Form;
+
+        let cases: Vec<(&str, Vec<&str>)> = vec![
+            ("(module main (block (val x 0)))", vec!["ModuleForm"]),
+            ("(block (val x 0))", vec!["BlockForm"]),
+            ("(import std.io)", vec!["ImportForm"]),
+            ("(export (list f g))", vec!["ExportForm"]),
+            ("(export f g)", vec![]),
+            ("(attrs f inline)", vec!["AttrsForm"]),
+            ("(type T Int)", vec!["TypeForm"]),
+            ("(sig f (Fun Int Int))", vec!["SigForm"]),
+            ("(val x 0)", vec!["ValForm"]),
+            ("(fun a (+ a 1))", vec!["FunForm"]),
+            ("(let (math.exp math.e 10))", vec!["LetForm", "AppForm"]),
+            ("(case res (match T id) (match E panic))", vec!["CaseForm"]),
+            ("(match True id)", vec![]),
+            ("(f a b)", vec!["AppForm"]),
+            ("(map (pair a A))", vec!["MapForm"]),
+            ("(vec a A)", vec!["VecForm"]),
+            ("(arr a A)", vec!["ArrForm"]),
+            ("(list a A)", vec!["ListForm"]),
+            ("(pair a A)", vec!["PairForm"]),
+            ("(Fun Int Int)", vec!["Type"]),
+            ("(Pair Int Char)", vec!["Type"]),
+        ];
+
+        for (s, expected) in cases.into_iter() {
+            let form = Form::from_str(s).unwrap();
+            let classes = FormValue::classify(&form);
+
+            assert_eq!(classes, expected, "classification of {}", s);
+
+            match FormValue::from_form(&form) {
+                Ok(value) => assert_eq!(Some(&value.kind_name()), classes.first()),
+                Err(_) => assert!(classes.is_empty()),
+            }
+        }
+    }
+}
@@ -1,9 +1,10 @@
 use crate::error::{Error, SyntacticError};
 use crate::loc::Loc;
 use crate::result::Result;
+use crate::syntax::is_form_keyword;
 use crate::token::Tokens;
 use crate::value::forms::case_form::CaseForm;
-use crate::value::forms::form::{Form, FormTailElement};
+use crate::value::forms::form::{with_form_delimiters, Form, FormTailElement};
 use crate::value::forms::fun_form::FunForm;
 use crate::value::forms::let_form::LetForm;
 use crate::value::forms::pair_form::PairForm;
@@ -279,44 +280,48 @@ impl AppForm {
             }));
         }
 
-        for var in form.tail[0..].iter() {
-            match var.clone() {
-                FormTailElement::Simple(value) => match value {
-                    SimpleValue::Ignore(_) => {
-                        self.variables.push(AppFormValue::Ignore(value));
+        for var in form.tail.iter() {
+            match var {
+                FormTailElement::Simple(value) => {
+                    let value = value.clone();
+
+                    match value {
+                        SimpleValue::Ignore(_) => {
+                            self.variables.push(AppFormValue::Ignore(value));
+                        }
+                        SimpleValue::Empty(_) => {
+                            self.variables.push(AppFormValue::Empty(value));
+                        }
+                        SimpleValue::Panic(_) => {
+                            self.variables.push(AppFormValue::Panic(value));
+                        }
+                        SimpleValue::Atomic(_) => {
+                            self.variables.push(AppFormValue::Atomic(value));
+                        }
+                        SimpleValue::ValueSymbol(_) => {
+                            self.variables.push(AppFormValue::ValueSymbol(value));
+                        }
+                        SimpleValue::ValuePathSymbol(_) => {
+                            self.variables.push(AppFormValue::ValuePathSymbol(value));
+                        }
+                        x => {
+                            return Err(Error::Syntactic(SyntacticError {
+                                loc: x.loc(),
+                                desc: "unexpected variable".into(),
+                            }));
+                        }
                     }
-                    SimpleValue::Empty(_) => {
-                        self.variables.push(AppFormValue::Empty(value));
-                    }
-                    SimpleValue::Panic(_) => {
-                        self.variables.push(AppFormValue::Panic(value));
-                    }
-                    SimpleValue::Atomic(_) => {
-                        self.variables.push(AppFormValue::Atomic(value));
-                    }
-                    SimpleValue::ValueSymbol(_) => {
-                        self.variables.push(AppFormValue::ValueSymbol(value));
-                    }
-                    SimpleValue::ValuePathSymbol(_) => {
-                        self.variables.push(AppFormValue::ValuePathSymbol(value));
-                    }
-                    x => {
-                        return Err(Error::Syntactic(SyntacticError {
-                            loc: x.loc(),
-                            desc: "unexpected variable".into(),
-                        }));
-                    }
-                },
+                }
                 FormTailElement::Form(form) => {
-                    if let Ok(form) = PairForm::from_form(&form) {
+                    if let Ok(form) = PairForm::from_form(form) {
                         self.variables.push(AppFormValue::PairForm(Box::new(form)));
-                    } else if let Ok(form) = FunForm::from_form(&form) {
+                    } else if let Ok(form) = FunForm::from_form(form) {
                         self.variables.push(AppFormValue::FunForm(Box::new(form)));
-                    } else if let Ok(form) = LetForm::from_form(&form) {
+                    } else if let Ok(form) = LetForm::from_form(form) {
                         self.variables.push(AppFormValue::LetForm(Box::new(form)));
-                    } else if let Ok(form) = CaseForm::from_form(&form) {
+                    } else if let Ok(form) = CaseForm::from_form(form) {
                         self.variables.push(AppFormValue::CaseForm(Box::new(form)));
-                    } else if let Ok(form) = AppForm::from_form(&form) {
+                    } else if let Ok(form) = AppForm::from_form(form) {
                         self.variables.push(AppFormValue::AppForm(Box::new(form)));
                     } else {
                         return Err(Error::Syntactic(SyntacticError {
@@ -348,6 +353,12 @@ impl AppForm {
             SimpleValue::Panic(_) => {
                 app.name = name;
             }
+            SimpleValue::ValueKeyword(_) if is_form_keyword(&name.to_string()) => {
+                return Err(Error::Syntactic(SyntacticError {
+                    loc: name.loc(),
+                    desc: format!("unexpected form keyword {}", name),
+                }));
+            }
             SimpleValue::ValueKeyword(_) => {
                 app.name = name;
             }
@@ -385,7 +396,10 @@ impl AppForm {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!("({} {})", self.name, self.variables_to_string(),)
+        with_form_delimiters(
+            &self.tokens,
+            format!("({} {})", self.name, self.variables_to_string(),),
+        )
     }
 }
 
@@ -403,6 +417,63 @@ impl std::str::FromStr for AppForm {
     }
 }
 
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct AppFormBuilder {
+    origin: Option<Loc>,
+    name: Option<SimpleValue>,
+    variables: Vec<AppFormValue>,
+}
+
+impl AppFormBuilder {
+    pub fn new() -> AppFormBuilder {
+        AppFormBuilder::default()
+    }
+
+    pub fn name(mut self, name: SimpleValue) -> AppFormBuilder {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn variable(mut self, variable: AppFormValue) -> AppFormBuilder {
+        self.variables.push(variable);
+        self
+    }
+
+    pub fn origin(mut self, origin: Loc) -> AppFormBuilder {
+        self.origin = Some(origin);
+        self
+    }
+
+    pub fn build(&self) -> Result<AppForm> {
+        let name = self.name.clone().ok_or_else(|| {
+            Error::Syntactic(SyntacticError {
+                loc: None,
+                desc: "expected an application name".into(),
+            })
+        })?;
+
+        if self.variables.is_empty() {
+            return Err(Error::Syntactic(SyntacticError {
+                loc: None,
+                desc: "expected at least a variable".into(),
+            }));
+        }
+
+        AppForm::from_tokens(&Tokens::from_str_with_origin(
+            &format!(
+                "({} {})",
+                name,
+                self.variables
+                    .iter()
+                    .map(|p| p.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            ),
+            self.origin.clone(),
+        )?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -492,4 +563,49 @@ mod tests {
         );
         assert_eq!(form.to_string(), s.to_string());
     }
+
+    #[test]
+    fn app_form_builder() {
+        use super::{AppFormBuilder, AppFormValue};
+        use crate::value::forms::PairForm;
+        use crate::value::SimpleValue;
+
+        let pair = PairForm::from_str("(pair a 'b')").unwrap();
+
+        let res = AppFormBuilder::new()
+            .name(SimpleValue::from_str("io.printf").unwrap())
+            .variable(AppFormValue::ValueSymbol(
+                SimpleValue::from_str("io").unwrap(),
+            ))
+            .variable(AppFormValue::Atomic(
+                SimpleValue::from_str("\"{} {}\"").unwrap(),
+            ))
+            .variable(AppFormValue::PairForm(Box::new(pair)))
+            .build();
+
+        assert!(res.is_ok());
+
+        let form = res.unwrap();
+
+        assert_eq!(form.name.to_string(), "io.printf".to_string());
+        assert_eq!(form.variables.len(), 3);
+        assert_eq!(
+            form.to_string(),
+            "(io.printf io \"{} {}\" (pair a 'b'))".to_string()
+        );
+
+        let a = AppFormValue::ValueSymbol(SimpleValue::from_str("a").unwrap());
+
+        assert!(SimpleValue::from_str("a b").is_err());
+        assert!(AppFormBuilder::new().variable(a.clone()).build().is_err());
+        assert!(AppFormBuilder::new()
+            .name(SimpleValue::from_str("f").unwrap())
+            .build()
+            .is_err());
+        assert!(AppFormBuilder::new()
+            .name(SimpleValue::from_str("F").unwrap())
+            .variable(a)
+            .build()
+            .is_err());
+    }
 }
@@ -4,7 +4,7 @@ use crate::result::Result;
 use crate::token::Tokens;
 use crate::value::forms::app_form::AppForm;
 use crate::value::forms::case_form::CaseForm;
-use crate::value::forms::form::{Form, FormTailElement};
+use crate::value::forms::form::{with_form_delimiters, Form, FormTailElement};
 use crate::value::forms::fun_form::FunForm;
 use crate::value::forms::let_form::LetForm;
 use crate::value::forms::list_form::ListForm;
@@ -171,30 +171,20 @@ impl ArrForm {
                 | ArrFormValue::CaseForm(_)
                 | ArrFormValue::LetForm(_)
                 | ArrFormValue::AppForm(_) => return false,
-                ArrFormValue::PairForm(form) => {
-                    if !form.can_be_parameter() {
-                        return false;
-                    }
+                ArrFormValue::PairForm(form) if !form.can_be_parameter() => {
+                    return false;
                 }
-                ArrFormValue::MapForm(form) => {
-                    if !form.can_be_parameter() {
-                        return false;
-                    }
+                ArrFormValue::MapForm(form) if !form.can_be_parameter() => {
+                    return false;
                 }
-                ArrFormValue::VecForm(form) => {
-                    if !form.can_be_parameter() {
-                        return false;
-                    }
+                ArrFormValue::VecForm(form) if !form.can_be_parameter() => {
+                    return false;
                 }
-                ArrFormValue::ListForm(form) => {
-                    if !form.can_be_parameter() {
-                        return false;
-                    }
+                ArrFormValue::ListForm(form) if !form.can_be_parameter() => {
+                    return false;
                 }
-                ArrFormValue::ArrForm(form) => {
-                    if !form.can_be_parameter() {
-                        return false;
-                    }
+                ArrFormValue::ArrForm(form) if !form.can_be_parameter() => {
+                    return false;
                 }
                 _ => {}
             }
@@ -501,7 +491,7 @@ impl ArrForm {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!("(arr {})", self.values_to_string())
+        with_form_delimiters(&self.tokens, format!("(arr {})", self.values_to_string()))
     }
 }
 
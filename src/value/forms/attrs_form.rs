@@ -1,8 +1,10 @@
 use crate::error::{Error, SyntacticError};
 use crate::loc::Loc;
 use crate::result::Result;
+use crate::syntax::{is_module_attribute, suggestions, MODULE_ATTRIBUTES};
 use crate::token::Tokens;
-use crate::value::forms::form::{Form, FormTailElement};
+use crate::value::forms::form::{with_form_delimiters, Form, FormTailElement};
+use crate::value::forms::list_form::{ListForm, ListFormValue};
 use crate::value::forms::map_form::MapForm;
 use crate::value::SimpleValue;
 use crate::value::Type;
@@ -132,6 +134,7 @@ pub struct AttrsForm {
     pub tokens: Box<Tokens>,
     pub name: SimpleValue,
     pub values: Vec<AttrsFormValue>,
+    pub values_tokens: Box<Tokens>,
 }
 
 impl AttrsForm {
@@ -155,7 +158,33 @@ impl AttrsForm {
         matches!(self.name, SimpleValue::ValueSymbol(_))
     }
 
+    pub fn is_module_attributes(&self) -> bool {
+        matches!(self.name, SimpleValue::ValueKeyword(_)) && self.name.to_string() == "module"
+    }
+
+    pub fn module_attributes(&self) -> Vec<String> {
+        if !self.is_module_attributes() {
+            return vec![];
+        }
+
+        self.values.iter().map(|value| value.to_string()).collect()
+    }
+
     pub fn values_to_string(&self) -> String {
+        if self.is_module_attributes() && self.values.len() > 1 {
+            return with_form_delimiters(
+                &self.values_tokens,
+                format!(
+                    "(list {})",
+                    self.values
+                        .iter()
+                        .map(|p| p.to_string())
+                        .collect::<Vec<String>>()
+                        .join(" ")
+                ),
+            );
+        }
+
         self.values
             .iter()
             .map(|p| p.to_string())
@@ -188,6 +217,66 @@ impl AttrsForm {
         vec![]
     }
 
+    fn parse_module_attribute(&mut self, value: SimpleValue) -> Result<()> {
+        let name = value.to_string();
+
+        if !matches!(value, SimpleValue::ValueSymbol(_)) || !is_module_attribute(&name) {
+            let suggestions = suggestions(&name, MODULE_ATTRIBUTES.iter().copied());
+
+            let desc = if suggestions.is_empty() {
+                format!("unknown module attribute {}", name)
+            } else {
+                format!(
+                    "unknown module attribute {}, did you mean {}?",
+                    name,
+                    suggestions.join(" or ")
+                )
+            };
+
+            return Err(Error::Syntactic(SyntacticError {
+                loc: value.loc(),
+                desc,
+            }));
+        }
+
+        if self.module_attributes().contains(&name) {
+            return Err(Error::Syntactic(SyntacticError {
+                loc: value.loc(),
+                desc: format!("duplicate module attribute {}", name),
+            }));
+        }
+
+        self.values.push(AttrsFormValue::ValueSymbol(value));
+
+        Ok(())
+    }
+
+    fn parse_module_attributes(&mut self, form: &Form) -> Result<()> {
+        match form.tail[1].clone() {
+            FormTailElement::Simple(value) => self.parse_module_attribute(value),
+            FormTailElement::Form(form) => {
+                let list = ListForm::from_form(&form)?;
+                self.values_tokens = list.tokens.clone();
+
+                for value in list.values {
+                    match value {
+                        ListFormValue::ValueSymbol(value) => {
+                            self.parse_module_attribute(value)?;
+                        }
+                        x => {
+                            return Err(Error::Syntactic(SyntacticError {
+                                loc: x.loc(),
+                                desc: "expected a module attribute".into(),
+                            }));
+                        }
+                    }
+                }
+
+                Ok(())
+            }
+        }
+    }
+
     pub fn from_form(form: &Form) -> Result<AttrsForm> {
         if form.head.to_string() != "attrs" {
             return Err(Error::Syntactic(SyntacticError {
@@ -214,6 +303,9 @@ impl AttrsForm {
                 SimpleValue::TypeSymbol(_) => {
                     attrs.name = value;
                 }
+                SimpleValue::ValueKeyword(_) if value.to_string() == "module" => {
+                    attrs.name = value;
+                }
                 x => {
                     return Err(Error::Syntactic(SyntacticError {
                         loc: x.loc(),
@@ -229,6 +321,12 @@ impl AttrsForm {
             }
         }
 
+        if attrs.is_module_attributes() {
+            attrs.parse_module_attributes(form)?;
+
+            return Ok(attrs);
+        }
+
         match form.tail[1].clone() {
             FormTailElement::Simple(value) => match value {
                 SimpleValue::Empty(_) => {
@@ -289,7 +387,10 @@ impl AttrsForm {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!("(attrs {} {})", self.name, self.values_to_string(),)
+        with_form_delimiters(
+            &self.tokens,
+            format!("(attrs {} {})", self.name, self.values_to_string(),),
+        )
     }
 }
 
@@ -368,4 +469,55 @@ mod tests {
         );
         assert_eq!(form.to_string(), s.to_string());
     }
+
+    #[test]
+    fn attrs_form_module_attributes() {
+        use super::AttrsForm;
+
+        let mut s = "(attrs module strict)";
+
+        let mut form = AttrsForm::from_str(s).unwrap();
+
+        assert!(form.is_module_attributes());
+        assert!(!form.is_value_attributes());
+        assert_eq!(form.module_attributes(), vec!["strict".to_string()]);
+        assert_eq!(form.to_string(), s.to_string());
+
+        s = "(attrs module (list entry noPrelude strict))";
+
+        form = AttrsForm::from_str(s).unwrap();
+
+        assert!(form.is_module_attributes());
+        assert_eq!(
+            form.module_attributes(),
+            vec!["entry".to_string(), "noPrelude".into(), "strict".into()]
+        );
+        assert_eq!(form.to_string(), s.to_string());
+
+        s = "(attrs module [list entry strict])";
+
+        form = AttrsForm::from_str(s).unwrap();
+
+        assert_eq!(form.values_to_string(), "[list entry strict]".to_string());
+        assert_eq!(form.to_string(), s.to_string());
+
+        form = AttrsForm::from_str("(attrs x strict)").unwrap();
+
+        assert!(!form.is_module_attributes());
+        assert!(form.module_attributes().is_empty());
+
+        match AttrsForm::from_str("(attrs module (list entry strct))") {
+            Err(crate::error::Error::Syntactic(err)) => {
+                assert_eq!(
+                    err.desc,
+                    "unknown module attribute strct, did you mean strict?".to_string()
+                );
+            }
+            _ => panic!("invalid branch"),
+        }
+
+        assert!(AttrsForm::from_str("(attrs module (list strict strict))").is_err());
+        assert!(AttrsForm::from_str("(attrs module (map (pair strict true)))").is_err());
+        assert!(AttrsForm::from_str("(attrs block strict)").is_err());
+    }
 }
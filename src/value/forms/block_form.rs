@@ -1,12 +1,14 @@
-use crate::error::{Error, SyntacticError};
+use crate::error::{Error, SemanticError, SyntacticError};
 use crate::loc::Loc;
-use crate::result::Result;
+use crate::result::{Diagnosed, Result};
+use crate::syntax::SyntaxConfig;
 use crate::token::Tokens;
 use crate::value::forms::app_form::AppFormValue;
 use crate::value::forms::attrs_form::AttrsForm;
 use crate::value::forms::export_form::ExportForm;
-use crate::value::forms::form::{Form, FormTailElement};
+use crate::value::forms::form::{with_form_delimiters, Form, FormTailElement};
 use crate::value::forms::import_form::ImportForm;
+use crate::value::forms::module_form::ModuleForm;
 use crate::value::forms::sig_form::SigForm;
 use crate::value::forms::type_form::TypeForm;
 use crate::value::forms::val_form::ValForm;
@@ -20,6 +22,7 @@ pub enum BlockFormEntry {
     ImportForm(Box<ImportForm>),
     ExportForm(Box<ExportForm>),
     AttrsForm(Box<AttrsForm>),
+    ModuleForm(Box<ModuleForm>),
     TypeForm(Box<TypeForm>),
     SigForm(Box<SigForm>),
     ValForm(Box<ValForm>),
@@ -38,6 +41,7 @@ impl BlockFormEntry {
             BlockFormEntry::ImportForm(form) => form.file(),
             BlockFormEntry::ExportForm(form) => form.file(),
             BlockFormEntry::AttrsForm(form) => form.file(),
+            BlockFormEntry::ModuleForm(form) => form.file(),
             BlockFormEntry::TypeForm(form) => form.file(),
             BlockFormEntry::SigForm(form) => form.file(),
             BlockFormEntry::ValForm(form) => form.file(),
@@ -50,6 +54,7 @@ impl BlockFormEntry {
             BlockFormEntry::ImportForm(form) => form.loc(),
             BlockFormEntry::ExportForm(form) => form.loc(),
             BlockFormEntry::AttrsForm(form) => form.loc(),
+            BlockFormEntry::ModuleForm(form) => form.loc(),
             BlockFormEntry::TypeForm(form) => form.loc(),
             BlockFormEntry::SigForm(form) => form.loc(),
             BlockFormEntry::ValForm(form) => form.loc(),
@@ -63,6 +68,7 @@ impl BlockFormEntry {
             BlockFormEntry::ImportForm(form) => form.to_string(),
             BlockFormEntry::ExportForm(form) => form.to_string(),
             BlockFormEntry::AttrsForm(form) => form.to_string(),
+            BlockFormEntry::ModuleForm(form) => form.to_string(),
             BlockFormEntry::TypeForm(form) => form.to_string(),
             BlockFormEntry::SigForm(form) => form.to_string(),
             BlockFormEntry::ValForm(form) => form.to_string(),
@@ -119,6 +125,43 @@ impl BlockForm {
         }
     }
 
+    pub fn entry_as_module(&self, idx: usize) -> Option<Box<ModuleForm>> {
+        if idx > self.entries.len() - 1 {
+            return None;
+        }
+
+        match self.entries[idx].clone() {
+            BlockFormEntry::ModuleForm(form) => Some(form),
+            _ => None,
+        }
+    }
+
+    pub fn modules(&self) -> Vec<ModuleForm> {
+        self.entries
+            .iter()
+            .filter_map(|entry| match entry {
+                BlockFormEntry::ModuleForm(form) => Some(*form.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn module_attributes(&self) -> Vec<String> {
+        let mut attributes = vec![];
+
+        for entry in self.entries.iter() {
+            if let BlockFormEntry::AttrsForm(form) = entry {
+                for attribute in form.module_attributes() {
+                    if !attributes.contains(&attribute) {
+                        attributes.push(attribute);
+                    }
+                }
+            }
+        }
+
+        attributes
+    }
+
     pub fn entry_as_type(&self, idx: usize) -> Option<Box<TypeForm>> {
         if idx > self.entries.len() - 1 {
             return None;
@@ -163,6 +206,17 @@ impl BlockForm {
         }
     }
 
+    pub fn entry_doc(&self, idx: usize) -> Tokens {
+        match self.entries.get(idx).and_then(|entry| entry.loc()) {
+            Some(loc) => self.tokens.doc_comments_before(&loc),
+            None => Tokens::new(),
+        }
+    }
+
+    pub fn entry_doc_string(&self, idx: usize) -> String {
+        self.entry_doc(idx).doc_comments_to_string()
+    }
+
     pub fn entries_to_string(&self) -> String {
         let len = self.entries.len();
 
@@ -277,6 +331,79 @@ impl BlockForm {
         vars
     }
 
+    pub fn check_parameters_use(&self) -> Diagnosed<()> {
+        let mut diagnosed = Diagnosed::new(());
+
+        for entry in self.entries.iter() {
+            if let BlockFormEntry::ValForm(form) = entry {
+                diagnosed.push_result(form.check_parameters_use());
+            }
+        }
+
+        diagnosed
+    }
+
+    pub fn check_definition_names(&self, config: &SyntaxConfig) -> Diagnosed<()> {
+        let mut diagnosed = Diagnosed::new(());
+
+        for entry in self.entries.iter() {
+            let name = match entry {
+                BlockFormEntry::TypeForm(form) => &form.name,
+                BlockFormEntry::SigForm(form) => &form.name,
+                BlockFormEntry::ValForm(form) => &form.name,
+                _ => continue,
+            };
+
+            diagnosed.push_result(config.check_definition_name(&name.to_string(), name.loc()));
+        }
+
+        diagnosed
+    }
+
+    pub fn check_glob_imports(&self) -> Diagnosed<()> {
+        let mut diagnosed = Diagnosed::new(());
+
+        let exports = self
+            .entries
+            .iter()
+            .any(|entry| matches!(entry, BlockFormEntry::ExportForm(_)));
+
+        let mut globs: Vec<String> = vec![];
+
+        for entry in self.entries.iter() {
+            if let BlockFormEntry::ImportForm(form) = entry {
+                if !form.is_glob() {
+                    continue;
+                }
+
+                let module = form.module_path();
+
+                if globs.contains(&module) {
+                    diagnosed.push(Error::Semantic(SemanticError {
+                        loc: form.loc(),
+                        desc: format!("duplicate glob import of {}", module),
+                    }));
+
+                    continue;
+                }
+
+                if exports {
+                    diagnosed.warn(Error::Semantic(SemanticError {
+                        loc: form.loc(),
+                        desc: format!(
+                            "glob import of {} in an exporting module: import symbols explicitly",
+                            module
+                        ),
+                    }));
+                }
+
+                globs.push(module);
+            }
+        }
+
+        diagnosed
+    }
+
     pub fn from_form(form: &Form) -> Result<BlockForm> {
         if form.head.to_string() != "block" {
             return Err(Error::Syntactic(SyntacticError {
@@ -310,6 +437,21 @@ impl BlockForm {
                         block_form
                             .entries
                             .push(BlockFormEntry::AttrsForm(Box::new(form)));
+                    } else if let Ok(form) = ModuleForm::from_form(form) {
+                        if block_form
+                            .modules()
+                            .iter()
+                            .any(|module| module.name.to_string() == form.name.to_string())
+                        {
+                            return Err(Error::Syntactic(SyntacticError {
+                                loc: form.loc(),
+                                desc: format!("duplicate module {}", form.name),
+                            }));
+                        }
+
+                        block_form
+                            .entries
+                            .push(BlockFormEntry::ModuleForm(Box::new(form)));
                     } else if let Ok(form) = TypeForm::from_form(form) {
                         block_form
                             .entries
@@ -356,7 +498,10 @@ impl BlockForm {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!("(block {})", self.entries_to_string())
+        with_form_delimiters(
+            &self.tokens,
+            format!("(block {})", self.entries_to_string()),
+        )
     }
 }
 
@@ -439,4 +584,142 @@ mod tests {
         assert!(form.entry_as_definition(5).unwrap().is_function_form());
         assert!(form.entry_as_definition(5).unwrap().is_value());
     }
+
+    #[test]
+    fn block_form_check_parameters_use() {
+        use super::BlockForm;
+
+        let mut s = "
+        (block
+            (val f (fun a b (g a b)))
+            (val h (fun a b (g b a)))
+            (val k (fun a (g a a))))";
+
+        let mut form = BlockForm::from_str(s).unwrap();
+
+        let diagnosed = form.check_parameters_use();
+
+        assert!(diagnosed.is_err());
+        assert_eq!(diagnosed.diagnostics.len(), 2);
+        assert_eq!(diagnosed.diagnostics[0].loc().unwrap().line, 3);
+        assert_eq!(diagnosed.diagnostics[1].loc().unwrap().line, 4);
+        assert!(diagnosed.into_result().is_err());
+
+        s = "(block (val f (fun a b (g a b))))";
+
+        form = BlockForm::from_str(s).unwrap();
+
+        assert!(form.check_parameters_use().into_result().is_ok());
+    }
+
+    #[test]
+    fn block_form_check_definition_names() {
+        use super::BlockForm;
+        use crate::error::Error;
+        use crate::syntax::SyntaxConfig;
+
+        let s = "
+        (block
+            (sig print (Fun IO String IO))
+            (val print (fun io s (printf io s)))
+            (val double (fun a (+ a a))))";
+
+        let form = BlockForm::from_str(s).unwrap();
+
+        let mut config = SyntaxConfig::new();
+
+        assert!(form.check_definition_names(&config).is_ok());
+
+        config.register_builtin("print").unwrap();
+
+        let diagnosed = form.check_definition_names(&config);
+
+        assert_eq!(diagnosed.diagnostics.len(), 2);
+
+        match &diagnosed.diagnostics[0] {
+            Error::Syntactic(err) => {
+                assert_eq!(err.desc, "cannot redefine builtin print".to_string());
+                assert_eq!(err.loc.as_ref().unwrap().line, 2);
+            }
+            _ => panic!("invalid branch"),
+        }
+    }
+
+    #[test]
+    fn block_form_check_glob_imports() {
+        use super::BlockForm;
+        use crate::error::Error;
+
+        let mut s = "
+        (block
+            (import std.io.*)
+            (import std.string.*)
+            (import std.io.*)
+            (val main (fun io (println io \"hi\"))))";
+
+        let mut form = BlockForm::from_str(s).unwrap();
+
+        let diagnosed = form.check_glob_imports();
+
+        assert_eq!(diagnosed.diagnostics.len(), 1);
+
+        match &diagnosed.diagnostics[0] {
+            Error::Semantic(err) => {
+                assert_eq!(err.desc, "duplicate glob import of std.io".to_string());
+                assert_eq!(err.loc.as_ref().unwrap().line, 4);
+            }
+            _ => panic!("invalid branch"),
+        }
+
+        s = "
+        (block
+            (import std.io.*)
+            (import std.io)
+            (export main)
+            (val main (fun io (println io \"hi\"))))";
+
+        form = BlockForm::from_str(s).unwrap();
+
+        let diagnosed = form.check_glob_imports();
+
+        assert!(diagnosed.is_ok());
+        assert_eq!(diagnosed.warnings.len(), 1);
+        assert_eq!(diagnosed.warnings[0].loc().unwrap().line, 2);
+
+        s = "(block (import std.io) (export main) (val main (fun io (println io \"hi\"))))";
+
+        form = BlockForm::from_str(s).unwrap();
+
+        let diagnosed = form.check_glob_imports();
+
+        assert!(diagnosed.is_ok());
+        assert!(diagnosed.warnings.is_empty());
+    }
+
+    #[test]
+    fn block_form_entry_doc() {
+        use super::BlockForm;
+
+        let s = "(block
+            #! Not attached: a blank line follows.
+
+            #! Doubles a number.
+            #!   a: the number
+            (val double (fun a (+ a a)))
+            (val e10 (math.exp math.e 10)))";
+
+        let form = BlockForm::from_str(s).unwrap();
+
+        let doc = form.entry_doc(0);
+
+        assert_eq!(doc.len(), 2);
+        assert_eq!(doc[0].loc().unwrap().line, 3);
+        assert_eq!(
+            form.entry_doc_string(0),
+            "Doubles a number.\n  a: the number".to_string()
+        );
+
+        assert!(form.entry_doc(1).is_empty());
+        assert!(form.entry_doc(2).is_empty());
+    }
 }
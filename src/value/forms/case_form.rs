@@ -3,7 +3,7 @@ use crate::loc::Loc;
 use crate::result::Result;
 use crate::token::Tokens;
 use crate::value::forms::app_form::AppForm;
-use crate::value::forms::form::{Form, FormTailElement};
+use crate::value::forms::form::{with_form_delimiters, Form, FormTailElement};
 use crate::value::forms::fun_form::FunForm;
 use crate::value::forms::let_form::LetForm;
 use crate::value::forms::pair_form::PairForm;
@@ -515,10 +515,13 @@ impl CaseFormMatch {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!(
-            "(match {} {})",
-            self.case.to_string(),
-            self.action.to_string()
+        with_form_delimiters(
+            &self.tokens,
+            format!(
+                "(match {} {})",
+                self.case.to_string(),
+                self.action.to_string()
+            ),
         )
     }
 }
@@ -724,10 +727,13 @@ impl CaseForm {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!(
-            "(case {} {})",
-            self.variable.to_string(),
-            self.matches_to_string(),
+        with_form_delimiters(
+            &self.tokens,
+            format!(
+                "(case {} {})",
+                self.variable.to_string(),
+                self.matches_to_string(),
+            ),
         )
     }
 }
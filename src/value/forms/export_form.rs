@@ -2,17 +2,123 @@ use crate::error::{Error, SyntacticError};
 use crate::loc::Loc;
 use crate::result::Result;
 use crate::token::Tokens;
-use crate::value::forms::form::{Form, FormTailElement};
-use crate::value::forms::list_form::{ListForm, ListFormValue};
+use crate::value::forms::form::{with_form_delimiters, Form, FormTailElement};
 use crate::value::SimpleValue;
 use crate::value::Type;
 use std::fmt;
 
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone, Default)]
+pub struct ExportFormAlias {
+    pub tokens: Box<Tokens>,
+    pub internal: SimpleValue,
+    pub public: SimpleValue,
+}
+
+impl ExportFormAlias {
+    pub fn new() -> ExportFormAlias {
+        ExportFormAlias::default()
+    }
+
+    pub fn file(&self) -> String {
+        self.tokens[0].file()
+    }
+
+    pub fn loc(&self) -> Option<Loc> {
+        self.tokens[0].loc()
+    }
+
+    pub fn is_type_alias(&self) -> bool {
+        matches!(self.public, SimpleValue::TypeSymbol(_))
+    }
+
+    pub fn from_form(form: &Form) -> Result<ExportFormAlias> {
+        if form.head.to_string() != "as" {
+            return Err(Error::Syntactic(SyntacticError {
+                loc: form.head.loc(),
+                desc: "expected an as symbol".into(),
+            }));
+        }
+
+        if form.tail.len() != 2 {
+            return Err(Error::Syntactic(SyntacticError {
+                loc: form.loc(),
+                desc: "expected an internal and a public symbol".into(),
+            }));
+        }
+
+        let mut alias = ExportFormAlias::new();
+        alias.tokens = form.tokens.clone();
+
+        match (form.tail[0].clone(), form.tail[1].clone()) {
+            (
+                FormTailElement::Simple(
+                    internal @ (SimpleValue::ValueSymbol(_) | SimpleValue::ValuePathSymbol(_)),
+                ),
+                FormTailElement::Simple(public @ SimpleValue::ValueSymbol(_)),
+            )
+            | (
+                FormTailElement::Simple(
+                    internal @ (SimpleValue::TypeSymbol(_) | SimpleValue::TypePathSymbol(_)),
+                ),
+                FormTailElement::Simple(public @ SimpleValue::TypeSymbol(_)),
+            ) => {
+                alias.internal = internal;
+                alias.public = public;
+            }
+            (_, x) => {
+                return Err(Error::Syntactic(SyntacticError {
+                    loc: x.loc(),
+                    desc: "expected an unqualified symbol of the same kind as the exported one"
+                        .into(),
+                }));
+            }
+        }
+
+        Ok(alias)
+    }
+
+    pub fn from_tokens(tokens: &Tokens) -> Result<ExportFormAlias> {
+        let form = Form::from_tokens(tokens)?;
+
+        ExportFormAlias::from_form(&form)
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<ExportFormAlias> {
+        let tokens = Tokens::from_str(s)?;
+
+        ExportFormAlias::from_tokens(&tokens)
+    }
+
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    pub fn to_string(&self) -> String {
+        with_form_delimiters(
+            &self.tokens,
+            format!("(as {} {})", self.internal, self.public),
+        )
+    }
+}
+
+impl fmt::Display for ExportFormAlias {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_string())
+    }
+}
+
+impl std::str::FromStr for ExportFormAlias {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_str(s)
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
 pub enum ExportFormDef {
     Empty(SimpleValue),
     ValueSymbol(SimpleValue),
     TypeSymbol(SimpleValue),
+    Alias(Box<ExportFormAlias>),
 }
 
 impl Default for ExportFormDef {
@@ -27,6 +133,7 @@ impl ExportFormDef {
             ExportFormDef::Empty(empty) => empty.file(),
             ExportFormDef::ValueSymbol(symbol) => symbol.file(),
             ExportFormDef::TypeSymbol(symbol) => symbol.file(),
+            ExportFormDef::Alias(alias) => alias.file(),
         }
     }
 
@@ -35,6 +142,7 @@ impl ExportFormDef {
             ExportFormDef::Empty(empty) => empty.loc(),
             ExportFormDef::ValueSymbol(symbol) => symbol.loc(),
             ExportFormDef::TypeSymbol(symbol) => symbol.loc(),
+            ExportFormDef::Alias(alias) => alias.loc(),
         }
     }
 
@@ -44,6 +152,7 @@ impl ExportFormDef {
             ExportFormDef::Empty(_) => "()".into(),
             ExportFormDef::ValueSymbol(symbol) => symbol.to_string(),
             ExportFormDef::TypeSymbol(symbol) => symbol.to_string(),
+            ExportFormDef::Alias(alias) => alias.to_string(),
         }
     }
 }
@@ -92,8 +201,14 @@ impl ExportForm {
         let mut value_vars = vec![];
 
         for def in self.defs.iter() {
-            if let ExportFormDef::ValueSymbol(value) = def.clone() {
-                value_vars.push(value);
+            match def.clone() {
+                ExportFormDef::ValueSymbol(value) => {
+                    value_vars.push(value);
+                }
+                ExportFormDef::Alias(alias) if !alias.is_type_alias() => {
+                    value_vars.push(alias.internal);
+                }
+                _ => {}
             }
         }
 
@@ -104,8 +219,14 @@ impl ExportForm {
         let mut type_vars = vec![];
 
         for def in self.defs.iter() {
-            if let ExportFormDef::TypeSymbol(value) = def.clone() {
-                type_vars.push(Type::from_simple_value(&value).unwrap());
+            match def.clone() {
+                ExportFormDef::TypeSymbol(value) => {
+                    type_vars.push(Type::from_simple_value(&value).unwrap());
+                }
+                ExportFormDef::Alias(alias) if alias.is_type_alias() => {
+                    type_vars.push(Type::from_simple_value(&alias.internal).unwrap());
+                }
+                _ => {}
             }
         }
 
@@ -123,6 +244,9 @@ impl ExportForm {
                 ExportFormDef::TypeSymbol(value) => {
                     vars.push(value);
                 }
+                ExportFormDef::Alias(alias) => {
+                    vars.push(alias.internal);
+                }
                 _ => {}
             }
         }
@@ -130,6 +254,24 @@ impl ExportForm {
         vars
     }
 
+    pub fn exported_names(&self) -> Vec<(String, String)> {
+        let mut names = vec![];
+
+        for def in self.defs.iter() {
+            match def {
+                ExportFormDef::ValueSymbol(value) | ExportFormDef::TypeSymbol(value) => {
+                    names.push((value.to_string(), value.to_string()));
+                }
+                ExportFormDef::Alias(alias) => {
+                    names.push((alias.public.to_string(), alias.internal.to_string()));
+                }
+                _ => {}
+            }
+        }
+
+        names
+    }
+
     pub fn from_form(form: &Form) -> Result<ExportForm> {
         if form.head.to_string() != "export" {
             return Err(Error::Syntactic(SyntacticError {
@@ -167,17 +309,37 @@ impl ExportForm {
                         }));
                     }
                 },
+                FormTailElement::Form(form) if form.head.to_string() == "as" => {
+                    let alias = ExportFormAlias::from_form(&form)?;
+                    export.defs.push(ExportFormDef::Alias(Box::new(alias)));
+                }
                 FormTailElement::Form(form) => {
-                    let list = ListForm::from_form(&form)?;
+                    if form.head.to_string() != "list" {
+                        return Err(Error::Syntactic(SyntacticError {
+                            loc: form.head.loc(),
+                            desc: "expected a list keyword".into(),
+                        }));
+                    }
+
+                    if form.tail.len() < 2 {
+                        return Err(Error::Syntactic(SyntacticError {
+                            loc: form.loc(),
+                            desc: "expected at least two values".into(),
+                        }));
+                    }
 
-                    for value in list.values {
-                        match value {
-                            ListFormValue::ValueSymbol(symbol) => {
+                    for elem in form.tail.iter().cloned() {
+                        match elem {
+                            FormTailElement::Simple(symbol @ SimpleValue::ValueSymbol(_)) => {
                                 export.defs.push(ExportFormDef::ValueSymbol(symbol));
                             }
-                            ListFormValue::TypeSymbol(symbol) => {
+                            FormTailElement::Simple(symbol @ SimpleValue::TypeSymbol(_)) => {
                                 export.defs.push(ExportFormDef::TypeSymbol(symbol));
                             }
+                            FormTailElement::Form(form) if form.head.to_string() == "as" => {
+                                let alias = ExportFormAlias::from_form(&form)?;
+                                export.defs.push(ExportFormDef::Alias(Box::new(alias)));
+                            }
                             x => {
                                 return Err(Error::Syntactic(SyntacticError {
                                     loc: x.loc(),
@@ -208,7 +370,7 @@ impl ExportForm {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!("(export {})", self.defs_to_string(),)
+        with_form_delimiters(&self.tokens, format!("(export {})", self.defs_to_string(),))
     }
 }
 
@@ -265,4 +427,65 @@ mod tests {
         assert_eq!(form.defs_to_string(), "()".to_string());
         assert_eq!(form.to_string(), s.to_string());
     }
+
+    #[test]
+    fn export_form_aliases() {
+        use super::{ExportForm, ExportFormDef};
+
+        let mut s = "(export (as helper publicHelper))";
+
+        let mut res = ExportForm::from_str(s);
+
+        assert!(res.is_ok());
+
+        let mut form = res.unwrap();
+
+        assert!(matches!(form.defs[0], ExportFormDef::Alias(_)));
+        assert_eq!(form.to_string(), s.to_string());
+        assert_eq!(
+            form.exported_names(),
+            vec![("publicHelper".to_string(), "helper".to_string())]
+        );
+
+        s = "(export (list a (as std.io.println println) (as Internal Public)))";
+
+        res = ExportForm::from_str(s);
+
+        assert!(res.is_ok());
+
+        form = res.unwrap();
+
+        assert_eq!(form.to_string(), s.to_string());
+        assert_eq!(
+            form.exported_names(),
+            vec![
+                ("a".to_string(), "a".to_string()),
+                ("println".into(), "std.io.println".into()),
+                ("Public".into(), "Internal".into()),
+            ]
+        );
+        assert_eq!(
+            form.all_value_variables()
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<String>>(),
+            vec!["a".to_string(), "std.io.println".into()]
+        );
+        assert_eq!(form.all_type_variables().len(), 1);
+
+        assert!(ExportForm::from_str("(export (as helper Public))").is_err());
+        assert!(ExportForm::from_str("(export (as helper public extra))").is_err());
+        assert!(ExportForm::from_str("(export (as helper std.io.public))").is_err());
+
+        match ExportForm::from_str("(export (list))") {
+            Err(crate::error::Error::Syntactic(err)) => {
+                assert_eq!(err.desc, "expected at least two values".to_string());
+            }
+            _ => panic!("invalid branch"),
+        }
+
+        assert!(ExportForm::from_str("(export (list f))").is_err());
+        assert!(ExportForm::from_str("(export (list (as f g)))").is_err());
+        assert!(ExportForm::from_str("(export (list f (as g h)))").is_ok());
+    }
 }
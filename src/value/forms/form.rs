@@ -1,16 +1,19 @@
 use crate::error::{Error, SyntacticError};
 use crate::loc::Loc;
 use crate::result::Result;
-use crate::syntax::{is_keyword, is_type_keyword};
+use crate::syntax::{
+    form_end_of, is_keyword, is_type_keyword, FORM_END, FORM_START, MAX_FORM_DEPTH,
+};
 use crate::syntax::{is_symbol, is_type_symbol, is_value_symbol, symbol_name};
 use crate::token::{TokenKind, Tokens};
-use crate::value::SimpleValue;
+use crate::value::{SimpleValue, TREE_INDENT};
 use std::fmt;
+use std::sync::Arc;
 
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
 pub enum FormTailElement {
     Simple(SimpleValue),
-    Form(Box<Form>),
+    Form(Arc<Form>),
 }
 
 impl FormTailElement {
@@ -28,6 +31,13 @@ impl FormTailElement {
         }
     }
 
+    pub fn to_tree_string(&self, depth: usize) -> String {
+        match self {
+            FormTailElement::Simple(value) => value.to_tree_string(depth),
+            FormTailElement::Form(form) => form.to_tree_string_at(depth),
+        }
+    }
+
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
         match self {
@@ -43,6 +53,29 @@ impl fmt::Display for FormTailElement {
     }
 }
 
+pub fn form_delimiters(tokens: &Tokens) -> (char, char) {
+    if tokens.is_empty() {
+        return (FORM_START, FORM_END);
+    }
+
+    let start = tokens[0].to_string();
+
+    match form_end_of(&start) {
+        Some(end) => (start.chars().next().unwrap(), end),
+        None => (FORM_START, FORM_END),
+    }
+}
+
+pub fn with_form_delimiters(tokens: &Tokens, s: String) -> String {
+    let (start, end) = form_delimiters(tokens);
+
+    if start == FORM_START || !s.starts_with(FORM_START) || !s.ends_with(FORM_END) {
+        return s;
+    }
+
+    format!("{}{}{}", start, &s[1..s.len() - 1], end)
+}
+
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone, Default)]
 pub struct Form {
     pub tokens: Box<Tokens>,
@@ -63,6 +96,10 @@ impl Form {
         self.tokens[0].loc()
     }
 
+    pub fn delimiters(&self) -> (char, char) {
+        form_delimiters(&self.tokens)
+    }
+
     pub fn tail_to_string(&self) -> String {
         self.tail
             .iter()
@@ -130,22 +167,63 @@ impl Form {
     }
 
     pub fn from_tokens(tokens: &Tokens) -> Result<Form> {
+        Form::from_tokens_with_depth_limit(tokens, MAX_FORM_DEPTH)
+    }
+
+    pub fn from_tokens_with_depth_limit(tokens: &Tokens, depth_limit: usize) -> Result<Form> {
+        Form::from_tokens_at(tokens, 1, depth_limit)
+    }
+
+    fn from_tokens_at(tokens: &Tokens, depth: usize, depth_limit: usize) -> Result<Form> {
+        if tokens.is_empty() {
+            return Err(Error::Syntactic(SyntacticError {
+                loc: None,
+                desc: "expected a form".into(),
+            }));
+        }
+
+        if tokens[0].kind == TokenKind::Shebang {
+            let shebang_loc = tokens[0].loc();
+            let tokens: Tokens = tokens
+                .clone()
+                .into_iter()
+                .skip(1)
+                .skip_while(|token| token.kind.is_comment())
+                .collect();
+
+            if tokens.is_empty() {
+                return Err(Error::Syntactic(SyntacticError {
+                    loc: shebang_loc,
+                    desc: "expected a form after the shebang".into(),
+                }));
+            }
+
+            return Form::from_tokens_at(&tokens, depth, depth_limit);
+        }
+
         let len = tokens.len();
 
-        if tokens[0].kind != TokenKind::FormStart {
+        if !tokens[0].kind.is_form_start() {
             return Err(Error::Syntactic(SyntacticError {
                 loc: tokens[0].loc(),
                 desc: "expected a form".into(),
             }));
         }
 
-        if tokens[len - 1].kind != TokenKind::FormEnd {
+        if tokens[0].kind.form_end() != Some(tokens[len - 1].kind) {
             return Err(Error::Syntactic(SyntacticError {
                 loc: tokens[len - 1].loc(),
                 desc: "expected a form".into(),
             }));
         }
 
+        if depth > depth_limit {
+            return Err(Error::Syntactic(SyntacticError {
+                loc: tokens[0].loc(),
+                desc: format!("form nesting exceeds depth limit {}", depth_limit),
+            }));
+        }
+
         let mut form = Form::new();
         form.tokens = Box::new(tokens.to_owned());
 
@@ -168,7 +246,7 @@ impl Form {
                 TokenKind::Comment | TokenKind::DocComment => {
                     idx += 1;
                 }
-                TokenKind::FormStart => {
+                kind if kind.is_form_start() => {
                     let mut count = 1;
 
                     let mut inner_tokens = Tokens::new();
@@ -181,9 +259,9 @@ impl Form {
                         inner_tokens.push(token.clone());
                         idx += 1;
 
-                        if token.kind == TokenKind::FormStart {
+                        if token.kind.is_form_start() {
                             count += 1;
-                        } else if token.kind == TokenKind::FormEnd {
+                        } else if token.kind.is_form_end() {
                             count -= 1;
 
                             if count == 0 {
@@ -192,10 +270,10 @@ impl Form {
                         }
                     }
 
-                    let inner_form = Form::from_tokens(&inner_tokens)?;
-                    form.tail.push(FormTailElement::Form(Box::new(inner_form)));
+                    let inner_form = Form::from_tokens_at(&inner_tokens, depth + 1, depth_limit)?;
+                    form.tail.push(FormTailElement::Form(Arc::new(inner_form)));
                 }
-                TokenKind::FormEnd => {
+                kind if kind.is_form_end() => {
                     idx += 1;
                     break;
                 }
@@ -227,16 +305,42 @@ impl Form {
         Form::from_tokens(&tokens)
     }
 
+    pub fn from_str_with_depth_limit(s: &str, depth_limit: usize) -> Result<Form> {
+        let tokens = Tokens::from_str_with_depth_limit(s, depth_limit)?;
+
+        Form::from_tokens_with_depth_limit(&tokens, depth_limit)
+    }
+
+    pub fn to_tree_string(&self) -> String {
+        self.to_tree_string_at(0)
+    }
+
+    fn to_tree_string_at(&self, depth: usize) -> String {
+        let mut lines = vec![format!("{}Form", TREE_INDENT.repeat(depth))];
+
+        lines.push(self.head.to_tree_string(depth + 1));
+
+        for elem in self.tail.iter() {
+            lines.push(elem.to_tree_string(depth + 1));
+        }
+
+        lines.join("\n")
+    }
+
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
+        let (start, end) = self.delimiters();
+
         format!(
-            "({} {})",
+            "{}{} {}{}",
+            start,
             self.head,
             self.tail
                 .iter()
                 .map(|p| p.to_string())
                 .collect::<Vec<String>>()
-                .join(" ")
+                .join(" "),
+            end
         )
     }
 }
@@ -257,6 +361,53 @@ impl std::str::FromStr for Form {
 
 #[cfg(test)]
 mod tests {
+    #[test]
+    fn form_is_send_sync() {
+        use super::Form;
+
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<Form>();
+    }
+
+    #[test]
+    fn form_delimiters() {
+        use super::Form;
+
+        let s = "[f a {g b (h c)}]";
+
+        let res = Form::from_str(s);
+
+        assert!(res.is_ok());
+
+        let form = res.unwrap();
+
+        assert_eq!(form.delimiters(), ('[', ']'));
+        assert_eq!(form.tail_to_string(), "a {g b (h c)}".to_string());
+        assert_eq!(form.to_string(), s.to_string());
+
+        assert!(Form::from_str("[f a)").is_err());
+    }
+
+    #[test]
+    fn form_from_str_with_shebang() {
+        use super::Form;
+
+        let form = Form::from_str("#!/usr/bin/env aster\n(f a b)").unwrap();
+
+        assert_eq!(form.head.to_string(), "f".to_string());
+        assert_eq!(form.loc().unwrap().line, 1);
+        assert_eq!(form.to_string(), "(f a b)".to_string());
+
+        let form = Form::from_str("#!/usr/bin/env aster\n# main module\n(f a b)").unwrap();
+
+        assert_eq!(form.loc().unwrap().line, 2);
+        assert_eq!(form.to_string(), "(f a b)".to_string());
+
+        assert!(Form::from_str("#!/usr/bin/env aster\n").is_err());
+        assert!(Form::from_str("").is_err());
+    }
+
     #[test]
     fn form_from_str() {
         use super::Form;
@@ -332,4 +483,21 @@ mod tests {
         assert!(form.is_types_form());
         assert_eq!(form.to_string(), s.to_string());
     }
+
+    #[test]
+    fn form_to_tree_string() {
+        use super::Form;
+        use crate::snapshot::assert_snapshot;
+        use std::fs;
+
+        let s = fs::read_to_string("./examples/hello_world.at").unwrap();
+
+        let form = Form::from_str(&s).unwrap();
+
+        assert_snapshot("hello_world_form", &form.to_tree_string());
+
+        let reparsed = Form::from_str(&form.to_string()).unwrap();
+
+        assert_eq!(reparsed.to_tree_string(), form.to_tree_string());
+    }
 }
@@ -5,7 +5,7 @@ use crate::token::Tokens;
 use crate::value::forms::app_form::AppForm;
 use crate::value::forms::arr_form::ArrForm;
 use crate::value::forms::case_form::CaseForm;
-use crate::value::forms::form::{Form, FormTailElement};
+use crate::value::forms::form::{with_form_delimiters, Form, FormTailElement};
 use crate::value::forms::let_form::LetForm;
 use crate::value::forms::list_form::ListForm;
 use crate::value::forms::map_form::MapForm;
@@ -705,10 +705,13 @@ impl FunForm {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!(
-            "(fun {} {})",
-            self.parameters_to_string(),
-            self.body.to_string(),
+        with_form_delimiters(
+            &self.tokens,
+            format!(
+                "(fun {} {})",
+                self.parameters_to_string(),
+                self.body.to_string(),
+            ),
         )
     }
 }
@@ -727,6 +730,58 @@ impl std::str::FromStr for FunForm {
     }
 }
 
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct FunFormBuilder {
+    origin: Option<Loc>,
+    parameters: Vec<FunFormParameter>,
+    body: Option<FunFormBody>,
+}
+
+impl FunFormBuilder {
+    pub fn new() -> FunFormBuilder {
+        FunFormBuilder::default()
+    }
+
+    pub fn parameter(mut self, parameter: FunFormParameter) -> FunFormBuilder {
+        self.parameters.push(parameter);
+        self
+    }
+
+    pub fn body(mut self, body: FunFormBody) -> FunFormBuilder {
+        self.body = Some(body);
+        self
+    }
+
+    pub fn origin(mut self, origin: Loc) -> FunFormBuilder {
+        self.origin = Some(origin);
+        self
+    }
+
+    pub fn build(&self) -> Result<FunForm> {
+        let body = self.body.clone().ok_or_else(|| {
+            Error::Syntactic(SyntacticError {
+                loc: None,
+                desc: "expected a function body".into(),
+            })
+        })?;
+
+        let parameters = if self.parameters.is_empty() {
+            "()".to_string()
+        } else {
+            self.parameters
+                .iter()
+                .map(|p| p.to_string())
+                .collect::<Vec<String>>()
+                .join(" ")
+        };
+
+        FunForm::from_tokens(&Tokens::from_str_with_origin(
+            &format!("(fun {} {})", parameters, body),
+            self.origin.clone(),
+        )?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -1486,4 +1541,58 @@ mod tests {
 
         assert!(form.check_parameters_use().is_err());
     }
+
+    #[test]
+    fn fun_form_builder() {
+        use super::{FunForm, FunFormBody, FunFormBuilder, FunFormParameter};
+        use crate::loc::Loc;
+        use crate::value::forms::AppForm;
+        use crate::value::SimpleValue;
+
+        let a = FunFormParameter::ValueSymbol(SimpleValue::from_str("a").unwrap());
+        let b = FunFormParameter::ValueSymbol(SimpleValue::from_str("b").unwrap());
+        let body = AppForm::from_str("(math.+ a b)").unwrap();
+
+        let res = FunFormBuilder::new()
+            .parameter(a.clone())
+            .parameter(b)
+            .body(FunFormBody::AppForm(Box::new(body)))
+            .build();
+
+        assert!(res.is_ok());
+
+        let form = res.unwrap();
+
+        assert_eq!(form.to_string(), "(fun a b (math.+ a b))".to_string());
+        assert_eq!(form, FunForm::from_str("(fun a b (math.+ a b))").unwrap());
+        assert!(form.check_parameters_use().is_ok());
+
+        let form = FunFormBuilder::new()
+            .body(FunFormBody::Panic(SimpleValue::from_str("panic").unwrap()))
+            .build()
+            .unwrap();
+
+        assert_eq!(form.to_string(), "(fun () panic)".to_string());
+
+        let origin = Loc {
+            file: Some("macro.at".into()),
+            line: 12,
+            pos: 8,
+            origin: None,
+        };
+
+        let form = FunFormBuilder::new()
+            .origin(origin.clone())
+            .parameter(a.clone())
+            .body(FunFormBody::ValueSymbol(
+                SimpleValue::from_str("a").unwrap(),
+            ))
+            .build()
+            .unwrap();
+
+        assert!(form.loc().unwrap().is_synthetic());
+        assert_eq!(form.loc().unwrap().source_loc(), origin);
+
+        assert!(FunFormBuilder::new().parameter(a).build().is_err());
+    }
 }
@@ -1,8 +1,9 @@
 use crate::error::{Error, SyntacticError};
 use crate::loc::Loc;
 use crate::result::Result;
+use crate::syntax::{is_glob_path, symbol_qualifier, GLOB_IMPORT_MARK};
 use crate::token::Tokens;
-use crate::value::forms::form::{Form, FormTailElement};
+use crate::value::forms::form::{with_form_delimiters, Form, FormTailElement};
 use crate::value::forms::list_form::{ListForm, ListFormValue};
 use crate::value::types::Type;
 use crate::value::SimpleValue;
@@ -80,6 +81,20 @@ impl ImportForm {
         self.tokens[0].loc()
     }
 
+    pub fn is_glob(&self) -> bool {
+        is_glob_path(&self.module.to_string())
+    }
+
+    pub fn module_path(&self) -> String {
+        let module = self.module.to_string();
+
+        if self.is_glob() {
+            symbol_qualifier(&module)
+        } else {
+            module
+        }
+    }
+
     pub fn type_variables_to_string(&self) -> String {
         match self.type_variables.len() {
             0 => "()".into(),
@@ -292,6 +307,12 @@ impl ImportForm {
 
         match form.tail[0].clone() {
             FormTailElement::Simple(value) => match value {
+                SimpleValue::ValueSymbol(_) if value.to_string() == GLOB_IMPORT_MARK => {
+                    return Err(Error::Syntactic(SyntacticError {
+                        loc: value.loc(),
+                        desc: "expected a module path before the glob".into(),
+                    }));
+                }
                 SimpleValue::ValueSymbol(_) => {
                     import.module = value;
                 }
@@ -313,6 +334,13 @@ impl ImportForm {
             }
         }
 
+        if import.is_glob() && len > 2 {
+            return Err(Error::Syntactic(SyntacticError {
+                loc: form.loc(),
+                desc: "expected a glob import without imported symbols or a qualifier".into(),
+            }));
+        }
+
         if len > 1 {
             match len {
                 2 => {
@@ -349,7 +377,7 @@ impl ImportForm {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        if let Some(ref qualifier) = self.qualifier {
+        let s = if let Some(ref qualifier) = self.qualifier {
             format!(
                 "(import {} {} {} {})",
                 self.module,
@@ -374,7 +402,9 @@ impl ImportForm {
                 self.type_variables_to_string(),
                 self.defs_to_string()
             )
-        }
+        };
+
+        with_form_delimiters(&self.tokens, s)
     }
 }
 
@@ -590,4 +620,44 @@ mod tests {
         assert_eq!(form.defs_to_string(), "_".to_string());
         assert_eq!(form.to_string(), s.to_string());
     }
+
+    #[test]
+    fn import_form_glob() {
+        use super::ImportForm;
+
+        let mut s = "(import std.io.*)";
+
+        let mut res = ImportForm::from_str(s);
+
+        assert!(res.is_ok());
+
+        let mut form = res.unwrap();
+
+        assert!(form.is_glob());
+        assert_eq!(form.module_path(), "std.io".to_string());
+        assert!(form.defs.is_empty());
+        assert_eq!(form.to_string(), s.to_string());
+
+        s = "(import std.x.* (list T Q))";
+
+        res = ImportForm::from_str(s);
+
+        assert!(res.is_ok());
+
+        form = res.unwrap();
+
+        assert!(form.is_glob());
+        assert_eq!(form.module_path(), "std.x".to_string());
+        assert_eq!(form.type_variables_to_string(), "(list T Q)");
+        assert_eq!(form.to_string(), s.to_string());
+
+        form = ImportForm::from_str("(import std.io)").unwrap();
+
+        assert!(!form.is_glob());
+        assert_eq!(form.module_path(), "std.io".to_string());
+
+        assert!(ImportForm::from_str("(import *)").is_err());
+        assert!(ImportForm::from_str("(import std.io.* () println)").is_err());
+        assert!(ImportForm::from_str("(import std.io.* () _ io)").is_err());
+    }
 }
@@ -6,7 +6,7 @@ use crate::value::forms::app_form::AppForm;
 use crate::value::forms::app_form::AppFormValue;
 use crate::value::forms::attrs_form::AttrsForm;
 use crate::value::forms::case_form::CaseForm;
-use crate::value::forms::form::{Form, FormTailElement};
+use crate::value::forms::form::{with_form_delimiters, Form, FormTailElement};
 use crate::value::forms::fun_form::FunForm;
 use crate::value::forms::import_form::ImportForm;
 use crate::value::forms::pair_form::PairForm;
@@ -427,7 +427,7 @@ impl LetForm {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        if self.entries.is_empty() {
+        let s = if self.entries.is_empty() {
             format!("(let {})", self.value.to_string(),)
         } else {
             format!(
@@ -435,7 +435,9 @@ impl LetForm {
                 self.entries_to_string(),
                 self.value.to_string(),
             )
-        }
+        };
+
+        with_form_delimiters(&self.tokens, s)
     }
 }
 
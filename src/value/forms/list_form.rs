@@ -5,7 +5,7 @@ use crate::token::Tokens;
 use crate::value::forms::app_form::AppForm;
 use crate::value::forms::arr_form::ArrForm;
 use crate::value::forms::case_form::CaseForm;
-use crate::value::forms::form::{Form, FormTailElement};
+use crate::value::forms::form::{with_form_delimiters, Form, FormTailElement};
 use crate::value::forms::fun_form::FunForm;
 use crate::value::forms::let_form::LetForm;
 use crate::value::forms::map_form::MapForm;
@@ -171,30 +171,20 @@ impl ListForm {
                 | ListFormValue::CaseForm(_)
                 | ListFormValue::LetForm(_)
                 | ListFormValue::AppForm(_) => return false,
-                ListFormValue::PairForm(form) => {
-                    if !form.can_be_parameter() {
-                        return false;
-                    }
+                ListFormValue::PairForm(form) if !form.can_be_parameter() => {
+                    return false;
                 }
-                ListFormValue::MapForm(form) => {
-                    if !form.can_be_parameter() {
-                        return false;
-                    }
+                ListFormValue::MapForm(form) if !form.can_be_parameter() => {
+                    return false;
                 }
-                ListFormValue::ArrForm(form) => {
-                    if !form.can_be_parameter() {
-                        return false;
-                    }
+                ListFormValue::ArrForm(form) if !form.can_be_parameter() => {
+                    return false;
                 }
-                ListFormValue::VecForm(form) => {
-                    if !form.can_be_parameter() {
-                        return false;
-                    }
+                ListFormValue::VecForm(form) if !form.can_be_parameter() => {
+                    return false;
                 }
-                ListFormValue::ListForm(form) => {
-                    if !form.can_be_parameter() {
-                        return false;
-                    }
+                ListFormValue::ListForm(form) if !form.can_be_parameter() => {
+                    return false;
                 }
                 _ => {}
             }
@@ -501,7 +491,7 @@ impl ListForm {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!("(list {})", self.values_to_string())
+        with_form_delimiters(&self.tokens, format!("(list {})", self.values_to_string()))
     }
 }
 
@@ -2,7 +2,7 @@ use crate::error::{Error, SyntacticError};
 use crate::loc::Loc;
 use crate::result::Result;
 use crate::token::Tokens;
-use crate::value::forms::form::{Form, FormTailElement};
+use crate::value::forms::form::{with_form_delimiters, Form, FormTailElement};
 use crate::value::forms::pair_form::{PairForm, PairFormValue};
 use crate::value::SimpleValue;
 use crate::value::Type;
@@ -342,7 +342,7 @@ impl MapForm {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!("(map {})", self.entries_to_string())
+        with_form_delimiters(&self.tokens, format!("(map {})", self.entries_to_string()))
     }
 }
 
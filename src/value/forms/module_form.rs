@@ -1,9 +1,10 @@
 use crate::error::{Error, SyntacticError};
 use crate::loc::Loc;
 use crate::result::Result;
+use crate::syntax::{symbol_with_qualifier, SYMBOL_PATH_SEPARATOR};
 use crate::token::Tokens;
 use crate::value::forms::block_form::{BlockForm, BlockFormEntry};
-use crate::value::forms::form::{Form, FormTailElement};
+use crate::value::forms::form::{with_form_delimiters, Form, FormTailElement};
 use crate::value::forms::list_form::{ListForm, ListFormValue};
 use crate::value::types::Type;
 use crate::value::SimpleValue;
@@ -89,6 +90,85 @@ impl ModuleForm {
         matches!(self.block, ModuleFormBlock::Empty(_))
     }
 
+    pub fn module_attributes(&self) -> Vec<String> {
+        match self.block.clone() {
+            ModuleFormBlock::Empty(_) => vec![],
+            ModuleFormBlock::Form(form) => form.module_attributes(),
+        }
+    }
+
+    pub fn is_entry(&self) -> bool {
+        self.module_attributes().contains(&"entry".to_string())
+    }
+
+    pub fn is_strict(&self) -> bool {
+        self.module_attributes().contains(&"strict".to_string())
+    }
+
+    pub fn uses_prelude(&self) -> bool {
+        !self.module_attributes().contains(&"noPrelude".to_string())
+    }
+
+    pub fn submodules(&self) -> Vec<ModuleForm> {
+        match self.block.clone() {
+            ModuleFormBlock::Empty(_) => vec![],
+            ModuleFormBlock::Form(form) => form.modules(),
+        }
+    }
+
+    pub fn module_paths(&self) -> Vec<String> {
+        let name = self.name.to_string();
+
+        let mut paths = vec![name.clone()];
+
+        for module in self.submodules() {
+            paths.extend(
+                module
+                    .module_paths()
+                    .iter()
+                    .map(|path| symbol_with_qualifier(path, &name)),
+            );
+        }
+
+        paths
+    }
+
+    pub fn find_module(&self, path: &str) -> Option<ModuleForm> {
+        let found = self.find_submodule(path);
+
+        if found.is_some() {
+            return found;
+        }
+
+        // paths from module_paths are qualified with this module's name
+        let name = self.name.to_string();
+
+        if path == name {
+            return Some(self.clone());
+        }
+
+        path.strip_prefix(&name)
+            .and_then(|rest| rest.strip_prefix(SYMBOL_PATH_SEPARATOR))
+            .and_then(|rest| self.find_submodule(rest))
+    }
+
+    fn find_submodule(&self, path: &str) -> Option<ModuleForm> {
+        let (head, rest) = match path.split_once(SYMBOL_PATH_SEPARATOR) {
+            Some((head, rest)) => (head, Some(rest)),
+            None => (path, None),
+        };
+
+        let module = self
+            .submodules()
+            .into_iter()
+            .find(|module| module.name.to_string() == head)?;
+
+        match rest {
+            Some(rest) => module.find_submodule(rest),
+            None => Some(module),
+        }
+    }
+
     pub fn all_parameters(&self) -> Vec<SimpleValue> {
         self.type_parameters
             .iter()
@@ -283,7 +363,7 @@ impl ModuleForm {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        if self.type_parameters.is_empty() {
+        let s = if self.type_parameters.is_empty() {
             format!("(module {} {})", self.name, self.block.to_string(),)
         } else {
             format!(
@@ -292,7 +372,9 @@ impl ModuleForm {
                 self.type_parameters_to_string(),
                 self.block.to_string(),
             )
-        }
+        };
+
+        with_form_delimiters(&self.tokens, s)
     }
 }
 
@@ -452,4 +534,129 @@ mod tests {
             "(import std.io _ println)".to_string()
         );
     }
+
+    #[test]
+    fn module_form_from_str_with_shebang() {
+        use super::ModuleForm;
+
+        let s = "#!/usr/bin/env aster
+        (module main (block
+            (import std.io)
+            (sig main (Fun IO IO))
+            (val main (fun io (println io \"Hello World!\")))))";
+
+        let form = ModuleForm::from_str(s).unwrap();
+
+        assert_eq!(form.name.to_string(), "main".to_string());
+        assert_eq!(form.block_entries().len(), 3);
+        assert!(ModuleForm::from_str(&form.to_string()).is_ok());
+
+        assert!(ModuleForm::from_str("#!/usr/bin/env aster\n").is_err());
+    }
+
+    #[test]
+    fn module_form_nested_modules() {
+        use super::ModuleForm;
+
+        let s = "
+        (module main (block
+            (module util (block
+                (module strings (block
+                    (val empty \"\")))
+                (val ident (fun x (f x)))))
+            (module io (block
+                (import std.io)))
+            (import util)
+            (val main (fun io (println io util.strings.empty)))))";
+
+        let res = ModuleForm::from_str(s);
+
+        assert!(res.is_ok());
+
+        let form = res.unwrap();
+
+        assert_eq!(form.submodules().len(), 2);
+        assert_eq!(
+            form.module_paths(),
+            vec![
+                "main".to_string(),
+                "main.util".into(),
+                "main.util.strings".into(),
+                "main.io".into()
+            ]
+        );
+
+        let block_entries = form.block_entries();
+
+        assert_eq!(block_entries.len(), 4);
+        assert_eq!(
+            block_entries[1].to_string(),
+            "(module io (block (import std.io)))".to_string()
+        );
+
+        let strings = form.find_module("util.strings").unwrap();
+
+        assert_eq!(strings.name.to_string(), "strings".to_string());
+        assert_eq!(strings.block_entries().len(), 1);
+        assert!(form.find_module("io").is_some());
+        assert!(form.find_module("util.io").is_none());
+        assert!(form.find_module("strings").is_none());
+        assert!(form.find_module("main.strings").is_none());
+        assert_eq!(
+            form.find_module("main.util.strings").unwrap().to_string(),
+            strings.to_string()
+        );
+        assert_eq!(
+            form.find_module("main").unwrap().to_string(),
+            form.to_string()
+        );
+
+        for path in form.module_paths() {
+            assert!(form.find_module(&path).is_some());
+        }
+
+        let reparsed = ModuleForm::from_str(&form.to_string()).unwrap();
+
+        assert_eq!(reparsed.module_paths(), form.module_paths());
+
+        assert!(ModuleForm::from_str(
+            "(module main (block (module a (block (val x 1))) (module a (block (val y 2)))))"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn module_form_module_attributes() {
+        use super::ModuleForm;
+
+        let mut s = "
+        (module main (block
+            (attrs module (list entry noPrelude))
+            (import std.io)
+            (val main (fun io (println io \"hi\")))))";
+
+        let mut form = ModuleForm::from_str(s).unwrap();
+
+        assert_eq!(
+            form.module_attributes(),
+            vec!["entry".to_string(), "noPrelude".into()]
+        );
+        assert!(form.is_entry());
+        assert!(!form.is_strict());
+        assert!(!form.uses_prelude());
+
+        s = "(module lib (block (attrs module strict) (val x 1)))";
+
+        form = ModuleForm::from_str(s).unwrap();
+
+        assert!(!form.is_entry());
+        assert!(form.is_strict());
+        assert!(form.uses_prelude());
+        assert_eq!(form.to_string(), s.to_string());
+
+        form = ModuleForm::from_str("(module lib ())").unwrap();
+
+        assert!(form.module_attributes().is_empty());
+        assert!(form.uses_prelude());
+    }
 }
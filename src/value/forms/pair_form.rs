@@ -5,7 +5,7 @@ use crate::token::Tokens;
 use crate::value::forms::app_form::AppForm;
 use crate::value::forms::arr_form::ArrForm;
 use crate::value::forms::case_form::CaseForm;
-use crate::value::forms::form::{Form, FormTailElement};
+use crate::value::forms::form::{with_form_delimiters, Form, FormTailElement};
 use crate::value::forms::fun_form::FunForm;
 use crate::value::forms::let_form::LetForm;
 use crate::value::forms::list_form::ListForm;
@@ -164,30 +164,20 @@ impl PairForm {
             | PairFormValue::CaseForm(_)
             | PairFormValue::LetForm(_)
             | PairFormValue::AppForm(_) => return false,
-            PairFormValue::PairForm(form) => {
-                if !form.can_be_parameter() {
-                    return false;
-                }
+            PairFormValue::PairForm(form) if !form.can_be_parameter() => {
+                return false;
             }
-            PairFormValue::MapForm(form) => {
-                if !form.can_be_parameter() {
-                    return false;
-                }
+            PairFormValue::MapForm(form) if !form.can_be_parameter() => {
+                return false;
             }
-            PairFormValue::ArrForm(form) => {
-                if !form.can_be_parameter() {
-                    return false;
-                }
+            PairFormValue::ArrForm(form) if !form.can_be_parameter() => {
+                return false;
             }
-            PairFormValue::VecForm(form) => {
-                if !form.can_be_parameter() {
-                    return false;
-                }
+            PairFormValue::VecForm(form) if !form.can_be_parameter() => {
+                return false;
             }
-            PairFormValue::ListForm(form) => {
-                if !form.can_be_parameter() {
-                    return false;
-                }
+            PairFormValue::ListForm(form) if !form.can_be_parameter() => {
+                return false;
             }
             _ => {}
         }
@@ -206,30 +196,20 @@ impl PairForm {
             | PairFormValue::CaseForm(_)
             | PairFormValue::LetForm(_)
             | PairFormValue::AppForm(_) => return false,
-            PairFormValue::PairForm(form) => {
-                if !form.can_be_parameter() {
-                    return false;
-                }
+            PairFormValue::PairForm(form) if !form.can_be_parameter() => {
+                return false;
             }
-            PairFormValue::MapForm(form) => {
-                if !form.can_be_parameter() {
-                    return false;
-                }
+            PairFormValue::MapForm(form) if !form.can_be_parameter() => {
+                return false;
             }
-            PairFormValue::ArrForm(form) => {
-                if !form.can_be_parameter() {
-                    return false;
-                }
+            PairFormValue::ArrForm(form) if !form.can_be_parameter() => {
+                return false;
             }
-            PairFormValue::VecForm(form) => {
-                if !form.can_be_parameter() {
-                    return false;
-                }
+            PairFormValue::VecForm(form) if !form.can_be_parameter() => {
+                return false;
             }
-            PairFormValue::ListForm(form) => {
-                if !form.can_be_parameter() {
-                    return false;
-                }
+            PairFormValue::ListForm(form) if !form.can_be_parameter() => {
+                return false;
             }
             _ => {}
         }
@@ -734,10 +714,13 @@ impl PairForm {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!(
-            "(pair {} {})",
-            self.first.to_string(),
-            self.second.to_string()
+        with_form_delimiters(
+            &self.tokens,
+            format!(
+                "(pair {} {})",
+                self.first.to_string(),
+                self.second.to_string()
+            ),
         )
     }
 }
@@ -1,8 +1,9 @@
 use crate::error::{Error, SyntacticError};
 use crate::loc::Loc;
 use crate::result::Result;
+use crate::syntax::SyntaxConfig;
 use crate::token::Tokens;
-use crate::value::forms::form::{Form, FormTailElement};
+use crate::value::forms::form::{with_form_delimiters, Form, FormTailElement};
 use crate::value::types::{SimpleType, Type};
 use crate::value::SimpleValue;
 use std::fmt;
@@ -87,7 +88,9 @@ impl SigForm {
                 SimpleValue::ValueSymbol(_) => {
                     sig_form.name = value;
                 }
-                _ => {
+                x => {
+                    SyntaxConfig::default().check_definition_name(&x.to_string(), x.loc())?;
+
                     return Err(Error::Syntactic(SyntacticError {
                         loc: form.loc(),
                         desc: "expected an unqualified value symbol".into(),
@@ -129,7 +132,7 @@ impl SigForm {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!("(sig {} {})", self.name, self.value)
+        with_form_delimiters(&self.tokens, format!("(sig {} {})", self.name, self.value))
     }
 }
 
@@ -1,8 +1,9 @@
 use crate::error::{Error, SyntacticError};
 use crate::loc::Loc;
 use crate::result::Result;
+use crate::syntax::SyntaxConfig;
 use crate::token::Tokens;
-use crate::value::forms::form::{Form, FormTailElement};
+use crate::value::forms::form::{with_form_delimiters, Form, FormTailElement};
 use crate::value::types::{SimpleType, Type};
 use crate::value::SimpleValue;
 use std::fmt;
@@ -91,6 +92,8 @@ impl TypeForm {
                     type_form.name = value;
                 }
                 x => {
+                    SyntaxConfig::default().check_definition_name(&x.to_string(), x.loc())?;
+
                     return Err(Error::Syntactic(SyntacticError {
                         loc: x.loc(),
                         desc: "expected an unqualified type symbol".into(),
@@ -132,7 +135,7 @@ impl TypeForm {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!("(type {} {})", self.name, self.value)
+        with_form_delimiters(&self.tokens, format!("(type {} {})", self.name, self.value))
     }
 }
 
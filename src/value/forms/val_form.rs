@@ -2,10 +2,11 @@ use crate::error::{Error, SemanticError, SyntacticError};
 use crate::loc::Loc;
 use crate::result::Result;
 use crate::syntax::is_value_symbol;
+use crate::syntax::SyntaxConfig;
 use crate::token::Tokens;
 use crate::value::forms::app_form::AppForm;
 use crate::value::forms::case_form::CaseForm;
-use crate::value::forms::form::{Form, FormTailElement};
+use crate::value::forms::form::{with_form_delimiters, Form, FormTailElement};
 use crate::value::forms::fun_form::FunForm;
 use crate::value::forms::let_form::LetForm;
 use crate::value::forms::pair_form::PairForm;
@@ -358,6 +359,8 @@ impl ValForm {
                     val.name = value;
                 }
                 x => {
+                    SyntaxConfig::default().check_definition_name(&x.to_string(), x.loc())?;
+
                     return Err(Error::Syntactic(SyntacticError {
                         loc: x.loc(),
                         desc: "expected an unqualified symbol".into(),
@@ -442,7 +445,10 @@ impl ValForm {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!("(val {} {})", self.name, self.value.to_string())
+        with_form_delimiters(
+            &self.tokens,
+            format!("(val {} {})", self.name, self.value.to_string()),
+        )
     }
 }
 
@@ -773,4 +779,20 @@ mod tests {
 
         assert!(form.check_parameters_use().is_err());
     }
+
+    #[test]
+    fn val_form_keyword_name() {
+        use super::ValForm;
+        use crate::error::{Error, SyntacticError};
+
+        let res = ValForm::from_str("(val map (fun a a))");
+
+        match res {
+            Err(Error::Syntactic(SyntacticError { loc, desc })) => {
+                assert_eq!(loc.unwrap().pos, 5);
+                assert_eq!(desc, "cannot define keyword map".to_string());
+            }
+            _ => panic!("invalid branch"),
+        }
+    }
 }
@@ -1,6 +1,7 @@
 pub mod form_value;
 pub mod forms;
 pub mod simple_value;
+pub mod syntax_form;
 pub mod types;
 #[allow(clippy::module_inception)]
 pub mod value;
@@ -8,5 +9,6 @@ pub mod value;
 pub use form_value::*;
 pub use forms::*;
 pub use simple_value::*;
+pub use syntax_form::SyntaxForm;
 pub use types::*;
 pub use value::*;
@@ -5,6 +5,8 @@ use crate::syntax::is_value_keyword;
 use crate::token::{Token, TokenKind, Tokens};
 use std::fmt;
 
+pub const TREE_INDENT: &str = "  ";
+
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
 pub enum SimpleValue {
     Ignore(Token),
@@ -103,7 +105,16 @@ impl SimpleValue {
                 loc: token.loc(),
                 desc: "unexpected comment marker".into(),
             })),
-            TokenKind::FormStart | TokenKind::FormEnd => Err(Error::Syntactic(SyntacticError {
+            TokenKind::Shebang => Err(Error::Syntactic(SyntacticError {
+                loc: token.loc(),
+                desc: "unexpected shebang".into(),
+            })),
+            TokenKind::FormStart
+            | TokenKind::FormEnd
+            | TokenKind::BracketFormStart
+            | TokenKind::BracketFormEnd
+            | TokenKind::BraceFormStart
+            | TokenKind::BraceFormEnd => Err(Error::Syntactic(SyntacticError {
                 loc: token.loc(),
                 desc: "unexpected form punctuation".into(),
             })),
@@ -126,6 +137,30 @@ impl SimpleValue {
         }
     }
 
+    pub fn kind_name(&self) -> &'static str {
+        match self {
+            SimpleValue::Ignore(_) => "Ignore",
+            SimpleValue::Empty(_) => "Empty",
+            SimpleValue::Panic(_) => "Panic",
+            SimpleValue::Atomic(_) => "Atomic",
+            SimpleValue::ValueKeyword(_) => "ValueKeyword",
+            SimpleValue::TypeKeyword(_) => "TypeKeyword",
+            SimpleValue::ValueSymbol(_) => "ValueSymbol",
+            SimpleValue::TypeSymbol(_) => "TypeSymbol",
+            SimpleValue::ValuePathSymbol(_) => "ValuePathSymbol",
+            SimpleValue::TypePathSymbol(_) => "TypePathSymbol",
+        }
+    }
+
+    pub fn to_tree_string(&self, depth: usize) -> String {
+        format!(
+            "{}{} {}",
+            TREE_INDENT.repeat(depth),
+            self.kind_name(),
+            self.to_string()
+        )
+    }
+
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
         match self {
@@ -0,0 +1,706 @@
+use crate::loc::Loc;
+use crate::token::Tokens;
+use crate::value::forms::*;
+use crate::value::{
+    ArrType, EnumType, FunType, ListType, MapType, PairType, SimpleType, Type, VecType,
+};
+use crate::value::{FormValue, SimpleValue, Value, TREE_INDENT};
+
+pub trait SyntaxForm {
+    fn kind_name(&self) -> &'static str;
+
+    fn file(&self) -> String;
+
+    fn loc(&self) -> Option<Loc>;
+
+    fn tokens(&self) -> Tokens;
+
+    fn to_string(&self) -> String;
+
+    fn children(&self) -> Vec<&dyn SyntaxForm>;
+
+    fn to_syntax_tree_string(&self, depth: usize) -> String {
+        let indent = TREE_INDENT.repeat(depth);
+        let children = self.children();
+
+        if children.is_empty() {
+            return format!("{}{} {}", indent, self.kind_name(), self.to_string());
+        }
+
+        let mut lines = vec![format!("{}{}", indent, self.kind_name())];
+
+        lines.extend(
+            children
+                .iter()
+                .map(|child| child.to_syntax_tree_string(depth + 1)),
+        );
+
+        lines.join("\n")
+    }
+}
+
+macro_rules! delegate_syntax_form {
+    ($name:ident, [$($variant:ident($x:ident) => $inner:expr),* $(,)?]) => {
+        impl $name {
+            fn syntax_inner(&self) -> &dyn SyntaxForm {
+                match self {
+                    $($name::$variant($x) => $inner,)*
+                }
+            }
+        }
+
+        impl SyntaxForm for $name {
+            fn kind_name(&self) -> &'static str {
+                self.syntax_inner().kind_name()
+            }
+
+            fn file(&self) -> String {
+                self.syntax_inner().file()
+            }
+
+            fn loc(&self) -> Option<Loc> {
+                self.syntax_inner().loc()
+            }
+
+            fn tokens(&self) -> Tokens {
+                self.syntax_inner().tokens()
+            }
+
+            fn to_string(&self) -> String {
+                self.syntax_inner().to_string()
+            }
+
+            fn children(&self) -> Vec<&dyn SyntaxForm> {
+                self.syntax_inner().children()
+            }
+        }
+    };
+}
+
+macro_rules! impl_syntax_form {
+    ($name:ident, |$this:ident| $children:expr) => {
+        impl SyntaxForm for $name {
+            fn kind_name(&self) -> &'static str {
+                stringify!($name)
+            }
+
+            fn file(&self) -> String {
+                self.file()
+            }
+
+            fn loc(&self) -> Option<Loc> {
+                self.loc()
+            }
+
+            fn tokens(&self) -> Tokens {
+                *self.tokens.clone()
+            }
+
+            fn to_string(&self) -> String {
+                self.to_string()
+            }
+
+            fn children(&self) -> Vec<&dyn SyntaxForm> {
+                let $this = self;
+                $children
+            }
+        }
+    };
+}
+
+impl SyntaxForm for SimpleValue {
+    fn kind_name(&self) -> &'static str {
+        self.kind_name()
+    }
+
+    fn file(&self) -> String {
+        self.file()
+    }
+
+    fn loc(&self) -> Option<Loc> {
+        self.loc()
+    }
+
+    fn tokens(&self) -> Tokens {
+        Tokens::from(vec![self.token()])
+    }
+
+    fn to_string(&self) -> String {
+        self.to_string()
+    }
+
+    fn children(&self) -> Vec<&dyn SyntaxForm> {
+        vec![]
+    }
+}
+
+delegate_syntax_form!(
+    Value,
+    [
+        Simple(x) => x,
+        Form(x) => x.as_ref(),
+    ]
+);
+
+delegate_syntax_form!(
+    FormValue,
+    [
+        ModuleForm(x) => x.as_ref(),
+        BlockForm(x) => x.as_ref(),
+        ImportForm(x) => x.as_ref(),
+        ExportForm(x) => x.as_ref(),
+        AttrsForm(x) => x.as_ref(),
+        TypeForm(x) => x.as_ref(),
+        SigForm(x) => x.as_ref(),
+        ValForm(x) => x.as_ref(),
+        FunForm(x) => x.as_ref(),
+        LetForm(x) => x.as_ref(),
+        CaseForm(x) => x.as_ref(),
+        AppForm(x) => x.as_ref(),
+        MapForm(x) => x.as_ref(),
+        VecForm(x) => x.as_ref(),
+        ArrForm(x) => x.as_ref(),
+        ListForm(x) => x.as_ref(),
+        PairForm(x) => x.as_ref(),
+        Type(x) => x.as_ref(),
+    ]
+);
+
+delegate_syntax_form!(
+    FormTailElement,
+    [
+        Simple(x) => x,
+        Form(x) => x.as_ref(),
+    ]
+);
+
+delegate_syntax_form!(
+    ModuleFormBlock,
+    [
+        Empty(x) => x,
+        Form(x) => x.as_ref(),
+    ]
+);
+
+delegate_syntax_form!(
+    BlockFormEntry,
+    [
+        Empty(x) => x,
+        ImportForm(x) => x.as_ref(),
+        ExportForm(x) => x.as_ref(),
+        AttrsForm(x) => x.as_ref(),
+        ModuleForm(x) => x.as_ref(),
+        TypeForm(x) => x.as_ref(),
+        SigForm(x) => x.as_ref(),
+        ValForm(x) => x.as_ref(),
+    ]
+);
+
+delegate_syntax_form!(
+    ImportFormDef,
+    [
+        Ignore(x) => x,
+        Empty(x) => x,
+        ValueSymbol(x) => x,
+        TypeSymbol(x) => x,
+    ]
+);
+
+delegate_syntax_form!(
+    ExportFormDef,
+    [
+        Empty(x) => x,
+        ValueSymbol(x) => x,
+        TypeSymbol(x) => x,
+        Alias(x) => x.as_ref(),
+    ]
+);
+
+delegate_syntax_form!(
+    AttrsFormValue,
+    [
+        Empty(x) => x,
+        Panic(x) => x,
+        Atomic(x) => x,
+        ValueSymbol(x) => x,
+        TypeSymbol(x) => x,
+        ValuePathSymbol(x) => x,
+        TypePathSymbol(x) => x,
+        Map(x) => x.as_ref(),
+    ]
+);
+
+delegate_syntax_form!(
+    ValFormValue,
+    [
+        Empty(x) => x,
+        Panic(x) => x,
+        Atomic(x) => x,
+        ValueSymbol(x) => x,
+        PairForm(x) => x.as_ref(),
+        FunForm(x) => x.as_ref(),
+        LetForm(x) => x.as_ref(),
+        AppForm(x) => x.as_ref(),
+        CaseForm(x) => x.as_ref(),
+    ]
+);
+
+delegate_syntax_form!(
+    FunFormParameter,
+    [
+        Empty(x) => x,
+        ValueSymbol(x) => x,
+        MapForm(x) => x.as_ref(),
+        VecForm(x) => x.as_ref(),
+        ArrForm(x) => x.as_ref(),
+        ListForm(x) => x.as_ref(),
+        PairForm(x) => x.as_ref(),
+    ]
+);
+
+delegate_syntax_form!(
+    FunFormBody,
+    [
+        Empty(x) => x,
+        Panic(x) => x,
+        Atomic(x) => x,
+        ValueSymbol(x) => x,
+        ValuePathSymbol(x) => x,
+        MapForm(x) => x.as_ref(),
+        VecForm(x) => x.as_ref(),
+        ArrForm(x) => x.as_ref(),
+        ListForm(x) => x.as_ref(),
+        PairForm(x) => x.as_ref(),
+        AppForm(x) => x.as_ref(),
+        LetForm(x) => x.as_ref(),
+        CaseForm(x) => x.as_ref(),
+        FunForm(x) => x.as_ref(),
+    ]
+);
+
+delegate_syntax_form!(
+    LetFormEntry,
+    [
+        Empty(x) => x,
+        ImportForm(x) => x.as_ref(),
+        AttrsForm(x) => x.as_ref(),
+        TypeForm(x) => x.as_ref(),
+        SigForm(x) => x.as_ref(),
+        ValForm(x) => x.as_ref(),
+    ]
+);
+
+delegate_syntax_form!(
+    CaseFormVariable,
+    [
+        Empty(x) => x,
+        Atomic(x) => x,
+        ValueSymbol(x) => x,
+        AppForm(x) => x.as_ref(),
+        LetForm(x) => x.as_ref(),
+        CaseForm(x) => x.as_ref(),
+    ]
+);
+
+delegate_syntax_form!(
+    CaseFormMatchCase,
+    [
+        Empty(x) => x,
+        Atomic(x) => x,
+        TypeKeyword(x) => x,
+        TypeSymbol(x) => x,
+        ValueSymbol(x) => x,
+        TypePathSymbol(x) => x,
+        ValuePathSymbol(x) => x,
+    ]
+);
+
+delegate_syntax_form!(
+    CaseFormMatchAction,
+    [
+        Ignore(x) => x,
+        Empty(x) => x,
+        Panic(x) => x,
+        Atomic(x) => x,
+        ValueKeyword(x) => x,
+        ValueSymbol(x) => x,
+        ValuePathSymbol(x) => x,
+        PairForm(x) => x.as_ref(),
+        FunForm(x) => x.as_ref(),
+        LetForm(x) => x.as_ref(),
+    ]
+);
+
+delegate_syntax_form!(
+    AppFormValue,
+    [
+        Ignore(x) => x,
+        Empty(x) => x,
+        Panic(x) => x,
+        Atomic(x) => x,
+        ValueSymbol(x) => x,
+        ValuePathSymbol(x) => x,
+        PairForm(x) => x.as_ref(),
+        FunForm(x) => x.as_ref(),
+        LetForm(x) => x.as_ref(),
+        CaseForm(x) => x.as_ref(),
+        AppForm(x) => x.as_ref(),
+    ]
+);
+
+delegate_syntax_form!(
+    MapFormEntry,
+    [
+        Ignore(x) => x,
+        Empty(x) => x,
+        PairForm(x) => x.as_ref(),
+    ]
+);
+
+delegate_syntax_form!(
+    VecFormValue,
+    [
+        Ignore(x) => x,
+        Empty(x) => x,
+        Panic(x) => x,
+        Atomic(x) => x,
+        ValueKeyword(x) => x,
+        TypeKeyword(x) => x,
+        ValueSymbol(x) => x,
+        TypeSymbol(x) => x,
+        ValuePathSymbol(x) => x,
+        TypePathSymbol(x) => x,
+        Type(x) => x.as_ref(),
+        FunForm(x) => x.as_ref(),
+        CaseForm(x) => x.as_ref(),
+        LetForm(x) => x.as_ref(),
+        AppForm(x) => x.as_ref(),
+        PairForm(x) => x.as_ref(),
+        ListForm(x) => x.as_ref(),
+        ArrForm(x) => x.as_ref(),
+        MapForm(x) => x.as_ref(),
+        VecForm(x) => x.as_ref(),
+    ]
+);
+
+delegate_syntax_form!(
+    ArrFormValue,
+    [
+        Ignore(x) => x,
+        Empty(x) => x,
+        Panic(x) => x,
+        Atomic(x) => x,
+        ValueKeyword(x) => x,
+        TypeKeyword(x) => x,
+        ValueSymbol(x) => x,
+        TypeSymbol(x) => x,
+        ValuePathSymbol(x) => x,
+        TypePathSymbol(x) => x,
+        Type(x) => x.as_ref(),
+        FunForm(x) => x.as_ref(),
+        CaseForm(x) => x.as_ref(),
+        LetForm(x) => x.as_ref(),
+        AppForm(x) => x.as_ref(),
+        PairForm(x) => x.as_ref(),
+        ListForm(x) => x.as_ref(),
+        VecForm(x) => x.as_ref(),
+        MapForm(x) => x.as_ref(),
+        ArrForm(x) => x.as_ref(),
+    ]
+);
+
+delegate_syntax_form!(
+    ListFormValue,
+    [
+        Ignore(x) => x,
+        Empty(x) => x,
+        Panic(x) => x,
+        Atomic(x) => x,
+        ValueKeyword(x) => x,
+        TypeKeyword(x) => x,
+        ValueSymbol(x) => x,
+        TypeSymbol(x) => x,
+        ValuePathSymbol(x) => x,
+        TypePathSymbol(x) => x,
+        Type(x) => x.as_ref(),
+        FunForm(x) => x.as_ref(),
+        CaseForm(x) => x.as_ref(),
+        LetForm(x) => x.as_ref(),
+        AppForm(x) => x.as_ref(),
+        PairForm(x) => x.as_ref(),
+        ArrForm(x) => x.as_ref(),
+        VecForm(x) => x.as_ref(),
+        MapForm(x) => x.as_ref(),
+        ListForm(x) => x.as_ref(),
+    ]
+);
+
+delegate_syntax_form!(
+    PairFormValue,
+    [
+        Ignore(x) => x,
+        Empty(x) => x,
+        Panic(x) => x,
+        Atomic(x) => x,
+        ValueKeyword(x) => x,
+        TypeKeyword(x) => x,
+        ValueSymbol(x) => x,
+        TypeSymbol(x) => x,
+        ValuePathSymbol(x) => x,
+        TypePathSymbol(x) => x,
+        Type(x) => x.as_ref(),
+        MapForm(x) => x.as_ref(),
+        VecForm(x) => x.as_ref(),
+        ArrForm(x) => x.as_ref(),
+        ListForm(x) => x.as_ref(),
+        PairForm(x) => x.as_ref(),
+        FunForm(x) => x.as_ref(),
+        CaseForm(x) => x.as_ref(),
+        LetForm(x) => x.as_ref(),
+        AppForm(x) => x.as_ref(),
+    ]
+);
+
+delegate_syntax_form!(
+    Type,
+    [
+        Simple(x) => x,
+        Enum(x) => x.as_ref(),
+        Pair(x) => x.as_ref(),
+        List(x) => x.as_ref(),
+        Arr(x) => x.as_ref(),
+        Vec(x) => x.as_ref(),
+        Map(x) => x.as_ref(),
+        Fun(x) => x.as_ref(),
+    ]
+);
+
+delegate_syntax_form!(
+    SimpleType,
+    [
+        Builtin(x) => x,
+        Ignore(x) => x,
+        Empty(x) => x,
+        Atomic(x) => x,
+        UInt(x) => x,
+        Int(x) => x,
+        Float(x) => x,
+        Size(x) => x,
+        Pointer(x) => x,
+        Ref(x) => x,
+        Char(x) => x,
+        String(x) => x,
+        Mem(x) => x,
+        Path(x) => x,
+        IO(x) => x,
+        Ctx(x) => x,
+        Type(x) => x,
+        Symbol(x) => x,
+        PathSymbol(x) => x,
+    ]
+);
+
+impl_syntax_form!(Form, |form| {
+    let mut children: Vec<&dyn SyntaxForm> = vec![];
+
+    children.push(&form.head);
+    children.extend(form.tail.iter().map(|x| x as &dyn SyntaxForm));
+
+    children
+});
+
+impl_syntax_form!(ModuleForm, |form| {
+    let mut children: Vec<&dyn SyntaxForm> = vec![];
+
+    children.push(&form.name);
+    children.extend(form.type_parameters.iter().map(|x| x as &dyn SyntaxForm));
+    children.push(&form.block);
+
+    children
+});
+
+impl_syntax_form!(BlockForm, |form| {
+    form.entries.iter().map(|x| x as &dyn SyntaxForm).collect()
+});
+
+impl_syntax_form!(ImportForm, |form| {
+    let mut children: Vec<&dyn SyntaxForm> = vec![];
+
+    children.push(&form.module);
+    if let Some(ref x) = form.qualifier {
+        children.push(x);
+    }
+    children.extend(form.type_variables.iter().map(|x| x as &dyn SyntaxForm));
+    children.extend(form.defs.iter().map(|x| x as &dyn SyntaxForm));
+
+    children
+});
+
+impl_syntax_form!(ExportFormAlias, |form| vec![&form.internal, &form.public]);
+
+impl_syntax_form!(ExportForm, |form| {
+    form.defs.iter().map(|x| x as &dyn SyntaxForm).collect()
+});
+
+impl_syntax_form!(AttrsForm, |form| {
+    let mut children: Vec<&dyn SyntaxForm> = vec![];
+
+    children.push(&form.name);
+    children.extend(form.values.iter().map(|x| x as &dyn SyntaxForm));
+
+    children
+});
+
+impl_syntax_form!(TypeForm, |form| vec![&form.name, form.value.as_ref()]);
+
+impl_syntax_form!(SigForm, |form| vec![&form.name, form.value.as_ref()]);
+
+impl_syntax_form!(ValForm, |form| vec![&form.name, &form.value]);
+
+impl_syntax_form!(FunForm, |form| {
+    let mut children: Vec<&dyn SyntaxForm> = vec![];
+
+    children.extend(form.parameters.iter().map(|x| x as &dyn SyntaxForm));
+    children.push(&form.body);
+
+    children
+});
+
+impl_syntax_form!(LetForm, |form| {
+    let mut children: Vec<&dyn SyntaxForm> = vec![];
+
+    children.extend(form.entries.iter().map(|x| x as &dyn SyntaxForm));
+    children.push(&form.value);
+
+    children
+});
+
+impl_syntax_form!(CaseForm, |form| {
+    let mut children: Vec<&dyn SyntaxForm> = vec![];
+
+    children.push(&form.variable);
+    children.extend(form.matches.iter().map(|x| x as &dyn SyntaxForm));
+
+    children
+});
+
+impl_syntax_form!(CaseFormMatch, |form| vec![&form.case, &form.action]);
+
+impl_syntax_form!(AppForm, |form| {
+    let mut children: Vec<&dyn SyntaxForm> = vec![];
+
+    children.push(&form.name);
+    children.extend(form.variables.iter().map(|x| x as &dyn SyntaxForm));
+
+    children
+});
+
+impl_syntax_form!(MapForm, |form| {
+    form.entries.iter().map(|x| x as &dyn SyntaxForm).collect()
+});
+
+impl_syntax_form!(VecForm, |form| {
+    form.values.iter().map(|x| x as &dyn SyntaxForm).collect()
+});
+
+impl_syntax_form!(ArrForm, |form| {
+    form.values.iter().map(|x| x as &dyn SyntaxForm).collect()
+});
+
+impl_syntax_form!(ListForm, |form| {
+    form.values.iter().map(|x| x as &dyn SyntaxForm).collect()
+});
+
+impl_syntax_form!(PairForm, |form| vec![&form.first, &form.second]);
+
+impl_syntax_form!(EnumType, |form| {
+    form.elements.iter().map(|x| x as &dyn SyntaxForm).collect()
+});
+
+impl_syntax_form!(PairType, |form| {
+    vec![form.first.as_ref(), form.second.as_ref()]
+});
+
+impl_syntax_form!(ListType, |form| {
+    form.elements.iter().map(|x| x as &dyn SyntaxForm).collect()
+});
+
+impl_syntax_form!(ArrType, |form| {
+    form.elements.iter().map(|x| x as &dyn SyntaxForm).collect()
+});
+
+impl_syntax_form!(VecType, |form| {
+    form.elements.iter().map(|x| x as &dyn SyntaxForm).collect()
+});
+
+impl_syntax_form!(MapType, |form| {
+    form.entries.iter().map(|x| x as &dyn SyntaxForm).collect()
+});
+
+impl_syntax_form!(FunType, |form| {
+    let mut children: Vec<&dyn SyntaxForm> = vec![];
+
+    children.extend(form.parameters.iter().map(|x| x as &dyn SyntaxForm));
+    children.push(form.body.as_ref());
+
+    children
+});
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn syntax_form_traversal() {
+        use super::SyntaxForm;
+        use crate::value::Value;
+        use std::fs;
+
+        fn leaves(form: &dyn SyntaxForm, acc: &mut Vec<String>) {
+            let children = form.children();
+
+            if children.is_empty() {
+                acc.push(form.to_string());
+            }
+
+            for child in children {
+                leaves(child, acc);
+            }
+        }
+
+        let s = fs::read_to_string("./examples/hello_world.at").unwrap();
+
+        let value = Value::from_str(&s).unwrap();
+        let form: &dyn SyntaxForm = &value;
+
+        assert_eq!(form.tokens().len(), 38);
+        assert_eq!(form.loc().unwrap().line, 0);
+        assert_eq!(form.children().len(), 2);
+
+        let mut acc = vec![];
+        leaves(form, &mut acc);
+
+        assert_eq!(
+            acc,
+            vec![
+                "main",
+                "std.io",
+                "std.string",
+                "main",
+                "IO",
+                "IO",
+                "main",
+                "io",
+                "println",
+                "io",
+                "\"Hello World!\"",
+            ]
+        );
+
+        let val = &form.children()[1].children()[3];
+
+        assert_eq!(
+            val.to_string(),
+            "(val main (fun io (println io \"Hello World!\")))"
+        );
+        assert_eq!(val.loc().unwrap().line, 7);
+    }
+}
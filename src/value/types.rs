@@ -3,7 +3,7 @@ use crate::error::{Error, SyntacticError};
 use crate::loc::Loc;
 use crate::result::Result;
 use crate::token::{Token, TokenKind, Tokens};
-use crate::value::forms::{Form, FormTailElement};
+use crate::value::forms::{with_form_delimiters, Form, FormTailElement};
 use crate::value::SimpleValue;
 use std::fmt;
 use std::iter;
@@ -320,13 +320,16 @@ impl EnumType {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!(
-            "(Enum {})",
-            self.elements
-                .iter()
-                .map(|p| p.to_string())
-                .collect::<Vec<String>>()
-                .join(" ")
+        with_form_delimiters(
+            &self.tokens,
+            format!(
+                "(Enum {})",
+                self.elements
+                    .iter()
+                    .map(|p| p.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            ),
         )
     }
 }
@@ -440,7 +443,10 @@ impl PairType {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!("(Pair {} {})", self.first, self.second)
+        with_form_delimiters(
+            &self.tokens,
+            format!("(Pair {} {})", self.first, self.second),
+        )
     }
 }
 
@@ -522,13 +528,16 @@ impl ListType {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!(
-            "(List {})",
-            self.elements
-                .iter()
-                .map(|p| p.to_string())
-                .collect::<Vec<String>>()
-                .join(" ")
+        with_form_delimiters(
+            &self.tokens,
+            format!(
+                "(List {})",
+                self.elements
+                    .iter()
+                    .map(|p| p.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            ),
         )
     }
 }
@@ -634,13 +643,16 @@ impl ArrType {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!(
-            "(Arr {})",
-            self.elements
-                .iter()
-                .map(|p| p.to_string())
-                .collect::<Vec<String>>()
-                .join(" ")
+        with_form_delimiters(
+            &self.tokens,
+            format!(
+                "(Arr {})",
+                self.elements
+                    .iter()
+                    .map(|p| p.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            ),
         )
     }
 }
@@ -746,13 +758,16 @@ impl VecType {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!(
-            "(Vec {})",
-            self.elements
-                .iter()
-                .map(|p| p.to_string())
-                .collect::<Vec<String>>()
-                .join(" ")
+        with_form_delimiters(
+            &self.tokens,
+            format!(
+                "(Vec {})",
+                self.elements
+                    .iter()
+                    .map(|p| p.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            ),
         )
     }
 }
@@ -858,13 +873,16 @@ impl MapType {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!(
-            "(Map {})",
-            self.entries
-                .iter()
-                .map(|p| p.to_string())
-                .collect::<Vec<String>>()
-                .join(" ")
+        with_form_delimiters(
+            &self.tokens,
+            format!(
+                "(Map {})",
+                self.entries
+                    .iter()
+                    .map(|p| p.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            ),
         )
     }
 }
@@ -993,7 +1011,10 @@ impl FunType {
 
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
-        format!("(Fun {} {})", self.parameters_to_string(), self.body)
+        with_form_delimiters(
+            &self.tokens,
+            format!("(Fun {} {})", self.parameters_to_string(), self.body),
+        )
     }
 }
 
@@ -1153,3 +1174,110 @@ impl fmt::Display for Type {
         write!(f, "{}", self.to_string())
     }
 }
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct TypeBuilder {
+    origin: Option<Loc>,
+    head: Option<SimpleValue>,
+    parameters: Vec<Type>,
+}
+
+impl TypeBuilder {
+    pub fn new() -> TypeBuilder {
+        TypeBuilder::default()
+    }
+
+    pub fn head(mut self, head: SimpleValue) -> TypeBuilder {
+        self.head = Some(head);
+        self
+    }
+
+    pub fn parameter(mut self, parameter: Type) -> TypeBuilder {
+        self.parameters.push(parameter);
+        self
+    }
+
+    pub fn origin(mut self, origin: Loc) -> TypeBuilder {
+        self.origin = Some(origin);
+        self
+    }
+
+    pub fn build(&self) -> Result<Type> {
+        let head = self.head.clone().ok_or_else(|| {
+            Error::Syntactic(SyntacticError {
+                loc: None,
+                desc: "expected a type head".into(),
+            })
+        })?;
+
+        if self.parameters.is_empty() {
+            let tokens = Tokens::from_str_with_origin(&head.to_string(), self.origin.clone())?;
+            let value = SimpleValue::from_token(&tokens[0])?;
+
+            Type::from_simple_value(&value)
+        } else {
+            let tokens = Tokens::from_str_with_origin(
+                &format!(
+                    "({} {})",
+                    head,
+                    self.parameters
+                        .iter()
+                        .map(|p| p.to_string())
+                        .collect::<Vec<String>>()
+                        .join(" ")
+                ),
+                self.origin.clone(),
+            )?;
+
+            Type::from_form(&Form::from_tokens(&tokens)?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn type_builder() {
+        use super::{Type, TypeBuilder};
+        use crate::value::SimpleValue;
+
+        let head = |s: &str| SimpleValue::from_str(s).unwrap();
+        let param = |s: &str| Type::from_simple_value(&head(s)).unwrap();
+
+        let pair = TypeBuilder::new()
+            .head(head("Pair"))
+            .parameter(param("A"))
+            .parameter(param("B"))
+            .build()
+            .unwrap();
+
+        assert!(matches!(pair, Type::Pair(_)));
+
+        let res = TypeBuilder::new()
+            .head(head("Fun"))
+            .parameter(param("moduleX.X"))
+            .parameter(param("Char"))
+            .parameter(pair)
+            .build();
+
+        assert!(res.is_ok());
+
+        let t = res.unwrap();
+
+        assert!(matches!(t, Type::Fun(_)));
+        assert_eq!(t.to_string(), "(Fun moduleX.X Char (Pair A B))".to_string());
+
+        let t = TypeBuilder::new().head(head("Char")).build().unwrap();
+
+        assert!(matches!(t, Type::Simple(_)));
+        assert_eq!(t.to_string(), "Char".to_string());
+
+        assert!(TypeBuilder::new().parameter(param("A")).build().is_err());
+        assert!(TypeBuilder::new().head(head("a")).build().is_err());
+        assert!(TypeBuilder::new()
+            .head(head("Pair"))
+            .parameter(param("A"))
+            .build()
+            .is_err());
+    }
+}
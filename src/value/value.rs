@@ -1,10 +1,14 @@
-use crate::error::Error;
+use crate::error::{Error, SyntacticError};
 use crate::loc::Loc;
 use crate::result::Result;
-use crate::token::{Token, Tokens};
+use crate::syntax::{FORM_END, FORM_START, MAX_FORM_DEPTH};
+use crate::token::{Token, TokenKind, Tokens};
 use crate::value::forms::Form;
-use crate::value::{FormValue, SimpleValue};
+use crate::value::{FormValue, SimpleValue, SyntaxForm};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
 pub enum Value {
@@ -45,6 +49,55 @@ impl Value {
         matches!(self, Value::Form(_))
     }
 
+    fn structural_string(&self) -> String {
+        self.tokens()
+            .into_iter()
+            .filter(|token| !token.kind.is_comment())
+            .map(|token| match token.kind {
+                kind if kind.is_form_start() => FORM_START.to_string(),
+                kind if kind.is_form_end() => FORM_END.to_string(),
+                _ => token.to_string(),
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    pub fn eq_ignoring_loc(&self, other: &Value) -> bool {
+        self.structural_string() == other.structural_string()
+    }
+
+    pub fn structural_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.structural_string().hash(&mut hasher);
+        hasher.finish()
+    }
+
+    pub fn dedup_structural(values: Vec<Value>) -> Vec<Value> {
+        let mut seen: HashMap<u64, Vec<usize>> = HashMap::new();
+        let mut deduped: Vec<Value> = Vec::new();
+
+        for value in values {
+            let hash = value.structural_hash();
+            let idxs = seen.entry(hash).or_default();
+
+            if idxs.iter().any(|&idx| deduped[idx].eq_ignoring_loc(&value)) {
+                continue;
+            }
+
+            idxs.push(deduped.len());
+            deduped.push(value);
+        }
+
+        deduped
+    }
+
+    pub fn to_tree_string(&self) -> String {
+        match self {
+            Value::Simple(value) => value.to_tree_string(0),
+            Value::Form(form) => form.to_syntax_tree_string(0),
+        }
+    }
+
     #[allow(clippy::inherent_to_string_shadow_display)]
     pub fn to_string(&self) -> String {
         match self {
@@ -60,11 +113,40 @@ impl Value {
         Value::from_tokens(&tokens)
     }
 
+    pub fn from_str_with_depth_limit(s: &str, depth_limit: usize) -> Result<Value> {
+        let tokens = Tokens::from_str_with_depth_limit(s, depth_limit)?;
+
+        Value::from_tokens_with_depth_limit(&tokens, depth_limit)
+    }
+
     pub fn from_tokens(tokens: &Tokens) -> Result<Value> {
+        Value::from_tokens_with_depth_limit(tokens, MAX_FORM_DEPTH)
+    }
+
+    pub fn from_tokens_with_depth_limit(tokens: &Tokens, depth_limit: usize) -> Result<Value> {
+        if !tokens.is_empty() && tokens[0].kind == TokenKind::Shebang {
+            let shebang_loc = tokens[0].loc();
+            let tokens: Tokens = tokens
+                .clone()
+                .into_iter()
+                .skip(1)
+                .skip_while(|token| token.kind.is_comment())
+                .collect();
+
+            if tokens.is_empty() {
+                return Err(Error::Syntactic(SyntacticError {
+                    loc: shebang_loc,
+                    desc: "expected a value after the shebang".into(),
+                }));
+            }
+
+            return Value::from_tokens_with_depth_limit(&tokens, depth_limit);
+        }
+
         let value = if tokens.len() == 1 {
             Value::from_token(&tokens[0])?
         } else {
-            let form = Form::from_tokens(tokens)?;
+            let form = Form::from_tokens_with_depth_limit(tokens, depth_limit)?;
 
             Value::from_form(&form)?
         };
@@ -150,4 +232,164 @@ mod tests {
 
         assert!(value.is_form());
     }
+
+    #[test]
+    fn value_from_str_with_shebang() {
+        use super::Value;
+
+        let s = "#!/usr/bin/env aster
+        (module main (block
+            (import std.io)
+            (sig main (Fun IO IO))
+            (val main (fun io (println io \"Hello World!\")))))";
+
+        let res = Value::from_str(s);
+
+        assert!(res.is_ok());
+        assert!(res.unwrap().is_form());
+
+        assert!(Value::from_str("#!/usr/bin/env aster\n").is_err());
+        assert!(Value::from_str("#!/usr/bin/env aster\nx")
+            .unwrap()
+            .is_simple());
+
+        let value = Value::from_str("#!/usr/bin/env aster\n# main module\n#! docs\n(f a)").unwrap();
+
+        assert_eq!(value.to_string(), "(f a)".to_string());
+        assert!(Value::from_str("#!/usr/bin/env aster\n# main module\n").is_err());
+    }
+
+    #[test]
+    fn value_from_str_depth_limit() {
+        use super::Value;
+        use crate::syntax::MAX_FORM_DEPTH;
+        use crate::token::Tokens;
+
+        let nested = |depth: usize| format!("{}a{}", "(f ".repeat(depth), ")".repeat(depth));
+
+        let value = Value::from_str(&nested(MAX_FORM_DEPTH)).unwrap();
+
+        assert!(value.is_form());
+        assert_eq!(value.to_string(), nested(MAX_FORM_DEPTH));
+
+        let depth = MAX_FORM_DEPTH / 3;
+        let s = format!(
+            "{}a{}",
+            "(case a (match b (fun c ".repeat(depth),
+            ")))".repeat(depth)
+        );
+
+        assert!(Value::from_str(&s).is_ok());
+
+        assert!(Value::from_str(&nested(MAX_FORM_DEPTH + 1)).is_err());
+
+        let tokens =
+            Tokens::from_str_with_depth_limit(&nested(MAX_FORM_DEPTH + 1), MAX_FORM_DEPTH * 2)
+                .unwrap();
+
+        match Value::from_tokens(&tokens) {
+            Err(err) => assert_eq!(
+                err.desc(),
+                format!("form nesting exceeds depth limit {}", MAX_FORM_DEPTH)
+            ),
+            _ => panic!("invalid branch"),
+        }
+
+        let deeper = nested(MAX_FORM_DEPTH * 2);
+
+        assert!(Value::from_str(&deeper).is_err());
+        assert_eq!(
+            Value::from_str_with_depth_limit(&deeper, MAX_FORM_DEPTH * 2)
+                .unwrap()
+                .to_string(),
+            deeper
+        );
+        assert!(Value::from_tokens_with_depth_limit(&tokens, MAX_FORM_DEPTH * 2).is_ok());
+        assert!(Value::from_str_with_depth_limit(&nested(4), 3).is_err());
+    }
+
+    #[test]
+    fn value_delimiters_round_trip() {
+        use super::Value;
+        use crate::value::forms::ValForm;
+
+        let sources = [
+            "[f a {g b}]",
+            "[val x {fun a [g a]}]",
+            "(module main {block [import std.io] (val main [fun io (println io \"hi\")])})",
+            "{sig f [Fun (Pair A B) {List C}]}",
+            "[pair a {list b c}]",
+        ];
+
+        for s in sources.iter() {
+            let value = Value::from_str(s).unwrap();
+
+            assert_eq!(value.to_string(), s.to_string());
+            assert!(value.eq_ignoring_loc(&Value::from_str(&value.to_string()).unwrap()));
+        }
+
+        let form = ValForm::from_str("[val x 1]").unwrap();
+
+        assert_eq!(form.to_string(), "[val x 1]".to_string());
+    }
+
+    #[test]
+    fn value_structural_identity() {
+        use super::Value;
+
+        let a = Value::from_str("(val f (fun a (g a)))").unwrap();
+        let b = Value::from_str("\n\n  (val f\n    (fun a # comment\n      (g a)))").unwrap();
+        let c = Value::from_str("(val f (fun b (g b)))").unwrap();
+
+        assert_ne!(a, b);
+        assert!(a.eq_ignoring_loc(&b));
+        assert_eq!(a.structural_hash(), b.structural_hash());
+        assert!(!a.eq_ignoring_loc(&c));
+
+        let d = Value::from_str("[val f {fun a [g a]}]").unwrap();
+
+        assert_ne!(a.to_string(), d.to_string());
+        assert!(a.eq_ignoring_loc(&d));
+        assert_eq!(a.structural_hash(), d.structural_hash());
+
+        let values = Value::dedup_structural(vec![a.clone(), c.clone(), b, d, a.clone()]);
+
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[0], a);
+        assert_eq!(values[1], c);
+    }
+
+    #[test]
+    fn value_to_tree_string() {
+        use super::Value;
+        use crate::snapshot::assert_snapshot;
+        use crate::value::{FormValue, SimpleValue, TypeBuilder};
+        use std::fs;
+
+        let s = fs::read_to_string("./examples/hello_world.at").unwrap();
+
+        let value = Value::from_str(&s).unwrap();
+
+        assert_snapshot("hello_world_value", &value.to_tree_string());
+
+        let value = Value::from_str("x").unwrap();
+
+        assert_eq!(value.to_tree_string(), "ValueSymbol x".to_string());
+
+        let char_type = TypeBuilder::new()
+            .head(SimpleValue::from_str("Char").unwrap())
+            .build()
+            .unwrap();
+
+        let value = Value::Form(Box::new(FormValue::Type(Box::new(char_type))));
+
+        assert_eq!(value.to_tree_string(), "TypeKeyword Char".to_string());
+
+        let value = Value::from_str("(f (g a) b)").unwrap();
+
+        assert_eq!(
+            value.to_tree_string(),
+            "AppForm\n  ValueSymbol f\n  AppForm\n    ValueSymbol g\n    ValueSymbol a\n  ValueSymbol b"
+        );
+    }
 }
@@ -0,0 +1,29 @@
+module.exports = grammar({
+  name: 'aster',
+
+  extras: $ => [/\s/],
+
+  rules: {
+    file: $ => seq(optional($.shebang), repeat($.element)),
+    element: $ => choice($.comment, $.doc_comment, $.value),
+    value: $ => choice($.form, $.atom),
+    form: $ => choice(seq('(', $.head, repeat($.element), ')'), seq('[', $.head, repeat($.element), ']'), seq('{', $.head, repeat($.element), '}')),
+    head: $ => choice($.keyword, $.symbol),
+    atom: $ => choice($.empty, $.uint, $.int, $.float, $.char, $.string, $.keyword, $.symbol),
+    empty: $ => token(prec(2, seq('(', ')'))),
+    shebang: $ => token(prec(3, seq('#!', '/', repeat(/[^\n]/), optional(/\n/)))),
+    comment: $ => token(prec(1, seq('#', repeat(/[^\n]/), optional(/\n/)))),
+    doc_comment: $ => token(prec(2, seq('#!', repeat(/[^\n]/), optional(/\n/)))),
+    keyword: $ => choice('module', 'block', '_', 'builtin', 'import', 'export', 'val', 'type', 'atomic', 'pair', 'list', 'arr', 'vec', 'map', 'sig', 'fun', 'attrs', 'app', 'case', 'id', 'default', 'match', 'others', 'size', 'load', 'store', 'ref', 'deref', 'cast', 'dup', 'drop', 'panic', 'Builtin', 'Empty', 'Atomic', 'UInt', 'Int', 'Float', 'Size', 'Pointer', 'Ref', 'Char', 'String', 'Mem', 'Path', 'IO', 'Ctx', 'Enum', 'Pair', 'List', 'Arr', 'Vec', 'Map', 'Fun', 'Type'),
+    uint: $ => token(choice(seq(/[0-9]/, repeat(/[0-9]/)), seq('b', /[01]/, repeat(/[01]/)), seq('o', /[0-7]/, repeat(/[0-7]/)), seq('x', /[a-f]/, repeat(/[a-f]/)), seq('X', /[0-9A-F]/, repeat(/[0-9A-F]/)))),
+    int: $ => token(seq(choice('+', '-'), choice(seq(/[0-9]/, repeat(/[0-9]/)), seq('b', /[01]/, repeat(/[01]/)), seq('o', /[0-7]/, repeat(/[0-7]/)), seq('x', /[a-f]/, repeat(/[a-f]/)), seq('X', /[0-9A-F]/, repeat(/[0-9A-F]/))))),
+    float: $ => token(seq(optional(choice('+', '-')), /[0-9]/, repeat(/[0-9]/), '.', /[0-9]/, repeat(/[0-9]/), optional(seq('E', optional(choice('+', '-')), /[0-9]/, repeat(/[0-9]/))))),
+    char: $ => token(prec(1, seq('\'', choice(seq('\\', /./), /./), '\''))),
+    string: $ => token(prec(1, seq('"', repeat(choice(seq('\\', /./), /[^"\\]/)), '"'))),
+    symbol: $ => choice($.value_symbol, $.type_symbol, $.value_path_symbol, $.type_path_symbol),
+    value_symbol: $ => token(choice(seq(/[a-z]/, repeat(/[A-Za-z0-9]/), optional(choice('!', '$', '%', '&', '*', '+', ',', '-', '/', ':', ';', '<', '=', '>', '?', '@', '\\', '^', '_', '`', '|', '~'))), seq(choice('!', '$', '%', '&', '*', '+', ',', '-', '/', ':', ';', '<', '=', '>', '?', '@', '\\', '^', '_', '`', '|', '~'), optional(seq(choice('!', '$', '%', '&', '*', '+', ',', '-', '/', ':', ';', '<', '=', '>', '?', '@', '\\', '^', '_', '`', '|', '~'), optional(choice('!', '$', '%', '&', '*', '+', ',', '-', '/', ':', ';', '<', '=', '>', '?', '@', '\\', '^', '_', '`', '|', '~'))))))),
+    type_symbol: $ => token(seq(/[A-Z]/, repeat(/[A-Za-z0-9]/))),
+    value_path_symbol: $ => token(seq(seq(/[a-z]/, repeat(/[A-Za-z]/), '.', repeat(seq(/[a-z]/, repeat(/[A-Za-z]/), '.'))), choice(seq(/[a-z]/, repeat(/[A-Za-z0-9]/), optional(choice('!', '$', '%', '&', '*', '+', ',', '-', '/', ':', ';', '<', '=', '>', '?', '@', '\\', '^', '_', '`', '|', '~'))), seq(choice('!', '$', '%', '&', '*', '+', ',', '-', '/', ':', ';', '<', '=', '>', '?', '@', '\\', '^', '_', '`', '|', '~'), optional(seq(choice('!', '$', '%', '&', '*', '+', ',', '-', '/', ':', ';', '<', '=', '>', '?', '@', '\\', '^', '_', '`', '|', '~'), optional(choice('!', '$', '%', '&', '*', '+', ',', '-', '/', ':', ';', '<', '=', '>', '?', '@', '\\', '^', '_', '`', '|', '~')))))))),
+    type_path_symbol: $ => token(seq(seq(/[a-z]/, repeat(/[A-Za-z]/), '.', repeat(seq(/[a-z]/, repeat(/[A-Za-z]/), '.'))), seq(/[A-Z]/, repeat(/[A-Za-z0-9]/)))),
+  },
+});
@@ -0,0 +1,17 @@
+(head (symbol (value_symbol) @function.call))
+(head (symbol (value_path_symbol) @function.call))
+(empty) @constant.builtin
+(shebang) @keyword.directive
+(comment) @comment
+(doc_comment) @comment.documentation
+(keyword) @keyword
+(uint) @number
+(int) @number
+(float) @number
+(char) @character
+(string) @string
+(value_symbol) @variable
+(type_symbol) @type
+(value_path_symbol) @variable
+(type_path_symbol) @type
+["(" ")" "[" "]" "{" "}"] @punctuation.bracket